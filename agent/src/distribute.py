@@ -174,6 +174,14 @@ class Distributor:
             pool_pubkey, pool_bump = self.solana_client.derive_pool_pda(pool_id)
             vault_pubkey, vault_bump = self.solana_client.derive_vault_pda(pool_pubkey)
             
+            # The creator state is seeded by the on-chain authority, not the DB's creator_wallet
+            authority = await self.solana_client.get_pool_authority(pool_pubkey)
+            if authority is None:
+                logger.error(f"Pool {pool_id} not found on-chain")
+                return False
+            creator_pda, _ = self.solana_client.derive_creator_pda(authority)
+            config_pda, _ = self.solana_client.derive_config_pda()
+            
             # Build instruction discriminator for distribute_rewards
             prefix = "global:distribute_rewards"
             hash_bytes = hashlib.sha256(prefix.encode()).digest()
@@ -186,6 +194,8 @@ class Distributor:
             accounts = [
                 AccountMeta(pubkey=pool_pubkey, is_signer=False, is_writable=True),
                 AccountMeta(pubkey=vault_pubkey, is_signer=False, is_writable=True),
+                AccountMeta(pubkey=creator_pda, is_signer=False, is_writable=True),
+                AccountMeta(pubkey=config_pda, is_signer=False, is_writable=True),
                 AccountMeta(
                     pubkey=self.solana_client.wallet.public_key,
                     is_signer=True,
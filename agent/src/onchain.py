@@ -75,6 +75,38 @@ class OnChainClient:
         else:
             return struct.pack('<B', 0)  # Default to competitive
     
+    def _encode_title(self, title: str) -> bytes:
+        """
+        Encode a pool title for Anchor.
+        
+        title: [u8; 32] - UTF-8, truncated on a character boundary, zero-padded
+        """
+        title_bytes = title.encode('utf-8')[:32].decode('utf-8', errors='ignore').encode('utf-8')
+        return title_bytes.ljust(32, b'\x00')
+    
+    def _encode_pool_options(
+        self,
+        title: str,
+        category: int,
+        is_public: bool,
+        max_total_staked_lamports: int,
+        early_bird_count: int,
+        early_bird_stake_lamports: int,
+        forbid_latecomers: bool,
+    ) -> bytes:
+        """
+        Encode the PoolOptions struct for Anchor (fields in declaration order).
+        """
+        return (
+            self._encode_title(title) +            # title: [u8; 32]
+            struct.pack('<B', category) +          # category: u8
+            struct.pack('<?', is_public) +         # is_public: bool
+            struct.pack('<Q', max_total_staked_lamports) +  # max_total_staked: u64
+            struct.pack('<H', early_bird_count) +  # early_bird_count: u16
+            struct.pack('<Q', early_bird_stake_lamports) +  # early_bird_stake: u64
+            struct.pack('<B', 1 if forbid_latecomers else 0)  # latecomer_policy: LatecomerPolicy
+        )
+    
     async def create_pool_on_chain(
         self,
         pool_id: int,
@@ -87,6 +119,13 @@ class OnChainClient:
         charity_address: str,
         distribution_mode: str = "competitive",
         winner_percent: int = 100,
+        title: str = "",
+        category: int = 0,
+        is_public: bool = True,
+        max_total_staked_lamports: int = 0,
+        early_bird_count: int = 0,
+        early_bird_stake_lamports: int = 0,
+        forbid_latecomers: bool = False,
     ) -> Optional[str]:
         """
         Create a pool on-chain.
@@ -101,14 +140,26 @@ class OnChainClient:
             min_participants: Min participants (1-max)
             charity_address: Charity wallet address
             distribution_mode: "competitive", "charity", or "split"
-            winner_percent: If split mode, percentage to winners (0-100)
+            winner_percent: If split mode, percentage to winners (1-99)
+            title: Display title for discovery (truncated to 32 bytes)
+            category: Discovery category used by indexers
+            is_public: Whether the pool is listed in the public directory
+            max_total_staked_lamports: Cap on total stake in lamports (0 = no cap)
+            early_bird_count: First N joiners pay the early-bird stake (0 = disabled)
+            early_bird_stake_lamports: Discounted stake for early joiners
+            forbid_latecomers: Reject joins after the pool's first day
         
         Returns:
             Transaction signature if successful, None otherwise
         """
         try:
-            # Derive pool PDA
+            # Derive pool, creator state, global config and vault PDAs
             pool_pubkey, _ = self.solana_client.derive_pool_pda(pool_id)
+            creator_pda, _ = self.solana_client.derive_creator_pda(
+                self.solana_client.wallet.public_key
+            )
+            config_pda, _ = self.solana_client.derive_config_pda()
+            vault_pubkey, _ = self.solana_client.derive_vault_pda(pool_pubkey)
             
             # Encode goal type
             if goal_type == "lifestyle_habit":
@@ -137,13 +188,25 @@ class OnChainClient:
                 struct.pack('<H', max_participants) +  # max_participants: u16
                 struct.pack('<H', min_participants) +  # min_participants: u16
                 bytes(charity_pubkey) +                # charity_address: Pubkey
-                dist_mode_bytes                        # distribution_mode: DistributionMode
+                dist_mode_bytes +                      # distribution_mode: DistributionMode
+                self._encode_pool_options(             # options: PoolOptions
+                    title,
+                    category,
+                    is_public,
+                    max_total_staked_lamports,
+                    early_bird_count,
+                    early_bird_stake_lamports,
+                    forbid_latecomers,
+                )
             )
             
             # Build account metas
             accounts = [
                 AccountMeta(pubkey=pool_pubkey, is_signer=False, is_writable=True),
                 AccountMeta(pubkey=self.solana_client.wallet.public_key, is_signer=True, is_writable=True),
+                AccountMeta(pubkey=creator_pda, is_signer=False, is_writable=True),
+                AccountMeta(pubkey=config_pda, is_signer=False, is_writable=True),
+                AccountMeta(pubkey=vault_pubkey, is_signer=False, is_writable=True),
                 AccountMeta(pubkey=SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
             ]
             
@@ -264,6 +327,12 @@ class OnChainClient:
                 pool_pubkey, participant_wallet_pubkey
             )
             
+            # Reference the day's evidence if the participant submitted any;
+            # Anchor reads the program ID in that slot as "no evidence"
+            evidence_pubkey, _ = self.solana_client.derive_evidence_pda(participant_pda, day)
+            if await self.solana_client.get_account_info(str(evidence_pubkey)) is None:
+                evidence_pubkey = self.program_id
+            
             # Build instruction data
             discriminator = self._anchor_discriminator("verify_participant")
             instruction_data = discriminator + struct.pack('<B', day) + struct.pack('<?', passed)
@@ -273,6 +342,7 @@ class OnChainClient:
                 AccountMeta(pubkey=pool_pubkey, is_signer=False, is_writable=True),
                 AccountMeta(pubkey=participant_pda, is_signer=False, is_writable=True),
                 AccountMeta(pubkey=self.solana_client.wallet.public_key, is_signer=True, is_writable=False),
+                AccountMeta(pubkey=evidence_pubkey, is_signer=False, is_writable=False),
             ]
             
             # Create instruction
@@ -316,6 +386,14 @@ class OnChainClient:
             pool_pubkey, _ = self.solana_client.derive_pool_pda(pool_id)
             vault_pubkey, _ = self.solana_client.derive_vault_pda(pool_pubkey)
             
+            # The creator state is seeded by the pool's on-chain authority
+            authority = await self.solana_client.get_pool_authority(pool_pubkey)
+            if authority is None:
+                logger.error(f"Pool {pool_id} not found on-chain")
+                return None
+            creator_pda, _ = self.solana_client.derive_creator_pda(authority)
+            config_pda, _ = self.solana_client.derive_config_pda()
+            
             # Build instruction data (just discriminator)
             discriminator = self._anchor_discriminator("distribute_rewards")
             instruction_data = discriminator
@@ -324,6 +402,8 @@ class OnChainClient:
             accounts = [
                 AccountMeta(pubkey=pool_pubkey, is_signer=False, is_writable=True),
                 AccountMeta(pubkey=vault_pubkey, is_signer=False, is_writable=True),
+                AccountMeta(pubkey=creator_pda, is_signer=False, is_writable=True),
+                AccountMeta(pubkey=config_pda, is_signer=False, is_writable=True),
                 AccountMeta(pubkey=self.solana_client.wallet.public_key, is_signer=True, is_writable=False),
                 AccountMeta(pubkey=SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
             ]
@@ -127,6 +127,27 @@ class SolanaClient:
             logger.error(f"Error fetching account info: {e}", exc_info=True)
             return None
 
+    async def get_pool_authority(self, pool_pubkey: Pubkey) -> Optional[Pubkey]:
+        """
+        Read a pool's authority from its on-chain account.
+        
+        The creator state PDA is seeded by this key, which can differ from
+        the creator wallet recorded in the database.
+        
+        Args:
+            pool_pubkey: The pool's PDA pubkey
+        
+        Returns:
+            The pool authority, or None if the pool account doesn't exist
+        """
+        account_info = await self.get_account_info(str(pool_pubkey))
+        if account_info is None:
+            return None
+        
+        # 8-byte discriminator and 1-byte layout version, then the authority
+        data = bytes(account_info["data"])
+        return Pubkey.from_bytes(data[9:41])
+
     async def get_program_accounts(self) -> List[Dict[str, Any]]:
         """
         Fetch all accounts owned by the Commitment Pool program.
@@ -294,6 +315,23 @@ class SolanaClient:
         pubkey, bump = Pubkey.find_program_address(seeds, program_id)
         return pubkey, bump
     
+    def derive_evidence_pda(self, participant_pubkey: Pubkey, day: int) -> Tuple[Pubkey, int]:
+        """
+        Derive the PDA for a participant's evidence commitment for a day.
+        
+        Args:
+            participant_pubkey: The participant account's PDA pubkey
+            day: Day number (1-indexed)
+        
+        Returns:
+            Tuple of (evidence_pubkey, bump)
+        """
+        program_id = Pubkey.from_string(self.program_id)
+        seeds = [b"evidence", bytes(participant_pubkey), bytes([day])]
+        
+        pubkey, bump = Pubkey.find_program_address(seeds, program_id)
+        return pubkey, bump
+    
     def derive_vault_pda(self, pool_pubkey: Pubkey) -> Tuple[Pubkey, int]:
         """
         Derive the PDA for a pool vault account.
@@ -310,6 +348,35 @@ class SolanaClient:
         pubkey, bump = Pubkey.find_program_address(seeds, program_id)
         return pubkey, bump
     
+    def derive_creator_pda(self, authority: Pubkey) -> Tuple[Pubkey, int]:
+        """
+        Derive the PDA for a creator's rate-limit state.
+        
+        Args:
+            authority: The pool creator's wallet pubkey
+        
+        Returns:
+            Tuple of (creator_state_pubkey, bump)
+        """
+        program_id = Pubkey.from_string(self.program_id)
+        seeds = [b"creator", bytes(authority)]
+        
+        pubkey, bump = Pubkey.find_program_address(seeds, program_id)
+        return pubkey, bump
+    
+    def derive_config_pda(self) -> Tuple[Pubkey, int]:
+        """
+        Derive the PDA for the global config (active pool cap).
+        
+        Returns:
+            Tuple of (global_config_pubkey, bump)
+        """
+        program_id = Pubkey.from_string(self.program_id)
+        seeds = [b"config"]
+        
+        pubkey, bump = Pubkey.find_program_address(seeds, program_id)
+        return pubkey, bump
+    
     def _anchor_instruction_discriminator(self, instruction_name: str) -> bytes:
         """Generate Anchor instruction discriminator (first 8 bytes of sha256("global:{name}"))"""
         prefix = f"global:{instruction_name}"
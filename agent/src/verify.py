@@ -57,6 +57,12 @@ class Verifier:
                 pool_pubkey, participant_wallet_pubkey
             )
             
+            # Reference the day's evidence if the participant submitted any;
+            # Anchor reads the program ID in that slot as "no evidence"
+            evidence_pubkey, _ = self.solana_client.derive_evidence_pda(participant_pubkey, day)
+            if await self.solana_client.get_account_info(str(evidence_pubkey)) is None:
+                evidence_pubkey = program_id
+            
             # Build instruction discriminator for verify_participant
             discriminator = self._anchor_instruction_discriminator("verify_participant")
             
@@ -72,6 +78,7 @@ class Verifier:
                     is_signer=True,
                     is_writable=False
                 ),
+                AccountMeta(pubkey=evidence_pubkey, is_signer=False, is_writable=False),
             ]
             
             # Create instruction
@@ -502,11 +502,14 @@ export default function CreatePool() {
         const creatorPubkey = walletAddress;
         const stakeLamports = solToLamports(stakeAmount);
         const minParticipants = minParticipantsForBackend;
-        // Use a valid base58 charity address (configurable via env, fallback to system program ID)
-        const charityAddress =
-          process.env.NEXT_PUBLIC_CHARITY_ADDRESS || '11111111111111111111111111111111';
-        const distributionMode = 'competitive';
+        const distributionMode: string = 'competitive';
         const winnerPercent = 100;
+        // Competitive pools must use the default (system program) address; the program rejects a charity.
+        // Other modes use the configured charity address (via env).
+        const charityAddress =
+          distributionMode === 'competitive'
+            ? '11111111111111111111111111111111'
+            : process.env.NEXT_PUBLIC_CHARITY_ADDRESS || '11111111111111111111111111111111';
 
         // Check wallet has enough balance for transaction fees + potential stake
         const requiredBalanceLamports = stakeLamports + 20_000_000; // Stake + tx fees
@@ -542,6 +545,7 @@ export default function CreatePool() {
           charity_address: charityAddress,
           distribution_mode: distributionMode,
           winner_percent: winnerPercent,
+          title: formData.name || '',
         };
 
         // Use fetch with timeout and retry for Render cold starts
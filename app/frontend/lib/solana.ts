@@ -209,14 +209,9 @@ function writeBigUInt64LE(buffer: Buffer, value: bigint, offset: number): void {
   view.setUint32(4, high, true);
 }
 
-function writeUInt32LE(buffer: Buffer, value: number, offset: number): void {
+function writeInt32LE(buffer: Buffer, value: number, offset: number): void {
   const view = new DataView(buffer.buffer, buffer.byteOffset + offset, 4);
-  view.setUint32(0, value, true);
-}
-
-function writeUInt16LE(buffer: Buffer, value: number, offset: number): void {
-  const view = new DataView(buffer.buffer, buffer.byteOffset + offset, 2);
-  view.setUint16(0, value, true);
+  view.setInt32(0, value, true);
 }
 
 function writeUInt8(buffer: Buffer, value: number, offset: number): void {
@@ -270,135 +265,26 @@ export async function deriveVaultPDA(poolPubkey: PublicKey): Promise<[PublicKey,
   return [pubkey, bump];
 }
 
-/**
- * Encode GoalType enum for Anchor
- */
-export function encodeGoalType(goalType: string, params: any): Buffer {
-  if (goalType === 'lifestyle_habit' || goalType === 'Lifestyle') {
-    // LifestyleHabit { habit_name: String }
-    const habitName = params.habit_name || params.name || 'Daily Habit';
-    const habitBytes = Buffer.from(habitName, 'utf-8');
-    const lengthBuffer = Buffer.allocUnsafe(4);
-    writeUInt32LE(lengthBuffer, habitBytes.length, 0);
-    return Buffer.concat([
-      Buffer.from([2]), // Variant index for LifestyleHabit
-      lengthBuffer,
-      habitBytes,
-    ]);
-  } else if (goalType === 'hodl_token' || goalType === 'Crypto') {
-    // HodlToken { token_mint: Pubkey, min_balance: u64 }
-    const tokenMint = new PublicKey(params.token_mint || 'So11111111111111111111111111111111111111112'); // Default to SOL
-    const minBalance = BigInt(params.min_balance || 0);
-    const minBalanceBuffer = Buffer.allocUnsafe(8);
-    writeBigUInt64LE(minBalanceBuffer, minBalance, 0);
-    return Buffer.concat([
-      Buffer.from([1]), // Variant index for HodlToken
-      tokenMint.toBuffer(),
-      minBalanceBuffer,
-    ]);
-  } else {
-    // Default to LifestyleHabit
-    const habitBytes = Buffer.from('Daily Habit', 'utf-8');
-    const lengthBuffer = Buffer.allocUnsafe(4);
-    writeUInt32LE(lengthBuffer, habitBytes.length, 0);
-    return Buffer.concat([
-      Buffer.from([2]),
-      lengthBuffer,
-      habitBytes,
-    ]);
-  }
-}
-
-/**
- * Encode DistributionMode enum for Anchor
- */
-export function encodeDistributionMode(mode: string, winnerPercent: number = 100): Buffer {
-  if (mode === 'competitive') {
-    return Buffer.from([0]);
-  } else if (mode === 'charity') {
-    return Buffer.from([1]);
-  } else if (mode === 'split') {
-    return Buffer.concat([
-      Buffer.from([2]),
-      Buffer.from([winnerPercent]),
-    ]);
-  } else {
-    return Buffer.from([0]); // Default to competitive
-  }
-}
-
-/**
- * Build create_pool instruction
- */
-export async function buildCreatePoolInstruction(
-  poolId: number,
-  creatorWallet: PublicKey,
-  goalType: string,
-  goalParams: any,
-  stakeAmountLamports: number,
-  durationDays: number,
-  maxParticipants: number,
-  minParticipants: number,
-  charityAddress: string,
-  distributionMode: string = 'competitive',
-  winnerPercent: number = 100
-): Promise<TransactionInstruction> {
-  const programId = getProgramId();
-  const [poolPDA] = await derivePoolPDA(poolId);
-  const charityPubkey = new PublicKey(charityAddress);
-  
-  // Build instruction data
-  const discriminator = await getInstructionDiscriminator('create_pool');
-  const poolIdBuffer = Buffer.allocUnsafe(8);
-  writeBigUInt64LE(poolIdBuffer, BigInt(poolId), 0);
-  
-  const goalTypeBytes = encodeGoalType(goalType, goalParams);
-  const stakeAmountBuffer = Buffer.allocUnsafe(8);
-  writeBigUInt64LE(stakeAmountBuffer, BigInt(stakeAmountLamports), 0);
-  const durationDaysBuffer = Buffer.from([durationDays]);
-  const maxParticipantsBuffer = Buffer.allocUnsafe(2);
-  writeUInt16LE(maxParticipantsBuffer, maxParticipants, 0);
-  const minParticipantsBuffer = Buffer.allocUnsafe(2);
-  writeUInt16LE(minParticipantsBuffer, minParticipants, 0);
-  const distModeBytes = encodeDistributionMode(distributionMode, winnerPercent);
-  
-  const instructionData = Buffer.concat([
-    discriminator,
-    poolIdBuffer,
-    goalTypeBytes,
-    stakeAmountBuffer,
-    durationDaysBuffer,
-    maxParticipantsBuffer,
-    minParticipantsBuffer,
-    charityPubkey.toBuffer(),
-    distModeBytes,
-  ]);
-  
-  return new TransactionInstruction({
-    programId,
-    keys: [
-      { pubkey: poolPDA, isSigner: false, isWritable: true },
-      { pubkey: creatorWallet, isSigner: true, isWritable: true },
-      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
-    ],
-    data: instructionData,
-  });
-}
+// create_pool transactions are built by the backend (/solana/actions/create-pool),
+// which tracks the program's current instruction layout.
 
 /**
  * Build join_pool instruction
  */
 export async function buildJoinPoolInstruction(
   poolId: number,
-  participantWallet: PublicKey
+  participantWallet: PublicKey,
+  tzOffsetSeconds: number = 0
 ): Promise<TransactionInstruction> {
   const programId = getProgramId();
   const [poolPDA] = await derivePoolPDA(poolId);
   const [vaultPDA] = await deriveVaultPDA(poolPDA);
   const [participantPDA] = await deriveParticipantPDA(poolPDA, participantWallet);
   
-  // Build instruction data (just discriminator for join_pool)
+  // Build instruction data: discriminator + tz_offset_seconds (i32)
   const discriminator = await getInstructionDiscriminator('join_pool');
+  const tzOffsetBuffer = Buffer.allocUnsafe(4);
+  writeInt32LE(tzOffsetBuffer, tzOffsetSeconds, 0);
   
   return new TransactionInstruction({
     programId,
@@ -409,7 +295,7 @@ export async function buildJoinPoolInstruction(
       { pubkey: vaultPDA, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ],
-    data: discriminator,
+    data: Buffer.concat([discriminator, tzOffsetBuffer]),
   });
 }
 
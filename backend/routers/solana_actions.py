@@ -145,7 +145,8 @@ async def describe_join_pool(
 )
 async def build_join_pool_tx(
     request_body: Dict[str, Any] = Body(...),
-    pool_id: int = Query(..., description="Pool ID to join")
+    pool_id: int = Query(..., description="Pool ID to join"),
+    tz_offset_seconds: int = Query(0, description="Participant's local offset from UTC in seconds"),
 ) -> Dict[str, Any]:
     """
     POST handler for the join-pool Action.
@@ -282,6 +283,7 @@ async def build_join_pool_tx(
             tx_b64 = await tx_builder.build_join_pool_transaction(
                 pool_id=pool_id_int,
                 participant_wallet=account,
+                tz_offset_seconds=tz_offset_seconds,
             )
         except Exception as e:
             logger.error(f"Failed to build join_pool transaction: {e}", exc_info=True)
@@ -353,6 +355,13 @@ async def build_create_pool_tx(request: Dict[str, Any]) -> Dict[str, Any]:
       - charity_address: string
       - distribution_mode: string (optional, default 'competitive')
       - winner_percent: int (optional, default 100)
+      - title: string (optional, truncated to 32 bytes)
+      - category: int (optional, default 0)
+      - is_public: bool (optional, default true)
+      - max_total_staked_lamports: int (optional, default 0 = no cap)
+      - early_bird_count: int (optional, default 0 = disabled)
+      - early_bird_stake_lamports: int (optional, default 0)
+      - forbid_latecomers: bool (optional, default false)
     """
     try:
         account = request.get("account")
@@ -366,6 +375,13 @@ async def build_create_pool_tx(request: Dict[str, Any]) -> Dict[str, Any]:
         charity_address = request.get("charity_address")
         distribution_mode = request.get("distribution_mode", "competitive")
         winner_percent = int(request.get("winner_percent", 100))
+        title = str(request.get("title") or "")
+        category = int(request.get("category", 0))
+        is_public = bool(request.get("is_public", True))
+        max_total_staked_lamports = int(request.get("max_total_staked_lamports", 0))
+        early_bird_count = int(request.get("early_bird_count", 0))
+        early_bird_stake_lamports = int(request.get("early_bird_stake_lamports", 0))
+        forbid_latecomers = bool(request.get("forbid_latecomers", False))
 
         if not account or not isinstance(account, str):
             raise HTTPException(status_code=400, detail="Missing or invalid 'account'")
@@ -400,6 +416,13 @@ async def build_create_pool_tx(request: Dict[str, Any]) -> Dict[str, Any]:
                 charity_address=charity_address,
                 distribution_mode=distribution_mode,
                 winner_percent=winner_percent,
+                title=title,
+                category=category,
+                is_public=is_public,
+                max_total_staked_lamports=max_total_staked_lamports,
+                early_bird_count=early_bird_count,
+                early_bird_stake_lamports=early_bird_stake_lamports,
+                forbid_latecomers=forbid_latecomers,
             )
         except HTTPException:
             raise
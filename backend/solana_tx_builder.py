@@ -69,6 +69,17 @@ class SolanaTransactionBuilder:
         seeds = [b"vault", bytes(pool_pubkey)]
         pubkey, bump = Pubkey.find_program_address(seeds, self.program_pubkey)
         return pubkey, bump
+    
+    def derive_creator_pda(self, authority: Pubkey) -> Tuple[Pubkey, int]:
+        """Derive the PDA for a creator's rate-limit state"""
+        seeds = [b"creator", bytes(authority)]
+        pubkey, bump = Pubkey.find_program_address(seeds, self.program_pubkey)
+        return pubkey, bump
+
+    def derive_config_pda(self) -> Tuple[Pubkey, int]:
+        """Derive the PDA for the global config (active pool cap)"""
+        pubkey, bump = Pubkey.find_program_address([b"config"], self.program_pubkey)
+        return pubkey, bump
 
     def _encode_goal_type_hodl(self, token_mint: str, min_balance: int) -> bytes:
         """
@@ -98,11 +109,44 @@ class SolanaTransactionBuilder:
         if mode == "split":
             return struct.pack("<BB", 2, winner_percent)
         return struct.pack("<B", 0)
+
+    def _encode_title(self, title: str) -> bytes:
+        """
+        Encode a pool title as [u8; 32]: UTF-8, truncated on a character
+        boundary and zero-padded. Matches OnChainClient._encode_title.
+        """
+        title_bytes = title.encode("utf-8")[:32].decode("utf-8", errors="ignore").encode("utf-8")
+        return title_bytes.ljust(32, b"\x00")
+
+    def _encode_pool_options(
+        self,
+        title: str,
+        category: int,
+        is_public: bool,
+        max_total_staked_lamports: int,
+        early_bird_count: int,
+        early_bird_stake_lamports: int,
+        forbid_latecomers: bool,
+    ) -> bytes:
+        """
+        Encode the PoolOptions struct (Borsh: fields in declaration order).
+        Matches OnChainClient._encode_pool_options.
+        """
+        return (
+            self._encode_title(title)                   # title: [u8; 32]
+            + struct.pack("<B", category)               # category: u8
+            + struct.pack("<?", is_public)              # is_public: bool
+            + struct.pack("<Q", max_total_staked_lamports)  # max_total_staked: u64
+            + struct.pack("<H", early_bird_count)       # early_bird_count: u16
+            + struct.pack("<Q", early_bird_stake_lamports)  # early_bird_stake: u64
+            + struct.pack("<B", 1 if forbid_latecomers else 0)  # latecomer_policy: LatecomerPolicy
+        )
     
     async def build_join_pool_transaction(
         self,
         pool_id: int,
         participant_wallet: str,
+        tz_offset_seconds: int = 0,
     ) -> str:
         """
         Build an unsigned transaction for joining a pool.
@@ -110,6 +154,7 @@ class SolanaTransactionBuilder:
         Args:
             pool_id: Pool ID to join
             participant_wallet: Wallet address of the participant (will be the fee payer)
+            tz_offset_seconds: Participant's local offset from UTC (within +/-14h)
         
         Returns:
             Base64-encoded unsigned transaction ready for wallet signing
@@ -122,9 +167,9 @@ class SolanaTransactionBuilder:
             vault_pubkey, _ = self.derive_vault_pda(pool_pubkey)
             participant_pda, _ = self.derive_participant_pda(pool_pubkey, participant_pubkey)
             
-            # Build instruction data (just discriminator - no args for join_pool)
+            # Build instruction data
             discriminator = self._anchor_discriminator("join_pool")
-            instruction_data = discriminator
+            instruction_data = discriminator + struct.pack("<i", tz_offset_seconds)  # tz_offset_seconds: i32
             
             # Build account metas (order matters - must match program's account order!)
             accounts = [
@@ -186,6 +231,13 @@ class SolanaTransactionBuilder:
         charity_address: str,
         distribution_mode: str = "competitive",
         winner_percent: int = 100,
+        title: str = "",
+        category: int = 0,
+        is_public: bool = True,
+        max_total_staked_lamports: int = 0,
+        early_bird_count: int = 0,
+        early_bird_stake_lamports: int = 0,
+        forbid_latecomers: bool = False,
     ) -> str:
         """
         Build an unsigned transaction for creating a pool.
@@ -196,8 +248,11 @@ class SolanaTransactionBuilder:
         try:
             creator_pubkey = Pubkey.from_string(creator_wallet)
 
-            # Derive pool PDA
+            # Derive pool, creator state, global config and vault PDAs
             pool_pubkey, _ = self.derive_pool_pda(pool_id)
+            creator_pda, _ = self.derive_creator_pda(creator_pubkey)
+            config_pda, _ = self.derive_config_pda()
+            vault_pubkey, _ = self.derive_vault_pda(pool_pubkey)
 
             # Encode goal type
             if goal_type == "lifestyle_habit":
@@ -229,12 +284,24 @@ class SolanaTransactionBuilder:
                 + struct.pack("<H", min_participants)       # min_participants: u16
                 + bytes(charity_pubkey)                     # charity_address: Pubkey
                 + dist_mode_bytes                           # distribution_mode: DistributionMode
+                + self._encode_pool_options(                # options: PoolOptions
+                    title,
+                    category,
+                    is_public,
+                    max_total_staked_lamports,
+                    early_bird_count,
+                    early_bird_stake_lamports,
+                    forbid_latecomers,
+                )
             )
 
-            # Accounts: pool PDA, creator wallet, system program
+            # Accounts: pool PDA, creator wallet, creator state PDA, global config PDA, vault PDA, system program
             accounts = [
                 AccountMeta(pubkey=pool_pubkey, is_signer=False, is_writable=True),
                 AccountMeta(pubkey=creator_pubkey, is_signer=True, is_writable=True),
+                AccountMeta(pubkey=creator_pda, is_signer=False, is_writable=True),
+                AccountMeta(pubkey=config_pda, is_signer=False, is_writable=True),
+                AccountMeta(pubkey=vault_pubkey, is_signer=False, is_writable=True),
                 AccountMeta(pubkey=SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False),
             ]
 
@@ -28,6 +28,90 @@ pub enum ErrorCode {
     
     #[msg("No winners to distribute rewards to")]
     NoWinners,
+    
+    #[msg("Pool has already been settled")]
+    PoolAlreadySettled,
+    
+    #[msg("Competitive pools cannot specify a charity address")]
+    CharityNotAllowed,
+    
+    #[msg("Distribution mode requires a charity address")]
+    CharityRequired,
+    
+    #[msg("Split winner percent must be between 1 and 99")]
+    InvalidWinnerPercent,
+    
+    #[msg("Timestamp is outside the pool's time window")]
+    InvalidTimestamp,
+    
+    #[msg("Self-rescue is not available for this pool yet")]
+    RescueNotAvailable,
+    
+    #[msg("Stake has already been rescued")]
+    AlreadyRescued,
+    
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    
+    #[msg("Competitive pools need at least 3 minimum participants")]
+    CompetitivePoolTooSmall,
+    
+    #[msg("Title must be zero-padded UTF-8")]
+    InvalidTitle,
+    
+    #[msg("Pool is no longer accepting participants")]
+    JoinWindowClosed,
+    
+    #[msg("Creating pools too quickly, try again later")]
+    RateLimited,
+    
+    #[msg("Pool has reached its total stake cap")]
+    PoolCapReached,
+    
+    #[msg("Stake amount can no longer be changed")]
+    StakeLocked,
+    
+    #[msg("Vault balance is insufficient")]
+    InsufficientVaultBalance,
+    
+    #[msg("Pool ID is reserved for the pool registry")]
+    InvalidPoolId,
+    
+    #[msg("Pool does not accept joins after its first day")]
+    LatecomerNotAllowed,
+    
+    #[msg("Batch is empty, too large, or missing participant accounts")]
+    InvalidBatchSize,
+    
+    #[msg("Participant account does not match the wallet's PDA")]
+    InvalidParticipantAccount,
+    
+    #[msg("Too many active pools; try again later")]
+    TooManyActivePools,
+    
+    #[msg("Pool is already on the current layout version")]
+    PoolAlreadyMigrated,
+    
+    #[msg("Timezone offset must be within 14 hours of UTC")]
+    InvalidTimezoneOffset,
+    
+    #[msg("Pool has been cancelled")]
+    PoolCancelled,
+    
+    #[msg("Goal parameters are out of range")]
+    InvalidGoalParameters,
+    
+    #[msg("Pool invariant violated")]
+    InvariantViolated,
+    
+    #[msg("Pool has not been abandoned long enough to release")]
+    PoolNotStale,
+    
+    #[msg("Participant is already on the current layout version")]
+    ParticipantAlreadyMigrated,
+    
+    #[msg("Wallet appears more than once in the batch")]
+    DuplicateBatchWallet,
 }
 
 
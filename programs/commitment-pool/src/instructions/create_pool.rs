@@ -1,7 +1,27 @@
 use anchor_lang::prelude::*;
+use crate::instructions::global_config::open_pool_slot;
+use crate::pda::*;
 use crate::state::*;
+use crate::vault;
 use crate::errors::ErrorCode;
 
+/// Smallest min_participants for a multi-person competitive pool.
+/// With only two people, both can agree on outcomes (or both forfeit) and split the pot.
+pub const MIN_COMPETITIVE_PARTICIPANTS: u16 = 3;
+
+/// Minimum seconds between pool creations by the same authority
+pub const MIN_CREATE_INTERVAL: i64 = 60;
+
+/// Largest DailyDCA amount or HodlToken min_balance, in token base units
+/// (1M tokens at 9 decimals). Goals above this are treated as unmeetable.
+pub const MAX_GOAL_AMOUNT: u64 = 1_000_000_000_000_000;
+
+/// Longest LifestyleHabit name, in bytes (fits the goal_type space estimate)
+pub const MAX_HABIT_NAME_LEN: usize = 64;
+
+/// First pool ID handed out by the registry; manual IDs must stay below it
+pub const REGISTRY_POOL_ID_START: u64 = 1 << 63;
+
 #[derive(Accounts)]
 #[instruction(pool_id: u64)]
 pub struct CreatePool<'info> {
@@ -9,7 +29,7 @@ pub struct CreatePool<'info> {
         init,
         payer = authority,
         space = CommitmentPool::LEN,
-        seeds = [b"pool", pool_id.to_le_bytes().as_ref()],
+        seeds = [POOL_SEED, pool_id.to_le_bytes().as_ref()],
         bump
     )]
     pub pool: Account<'info, CommitmentPool>,
@@ -17,9 +37,35 @@ pub struct CreatePool<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CreatorState::LEN,
+        seeds = [CREATOR_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub creator_state: Account<'info, CreatorState>,
+    
+    /// CHECK: Global config PDA; may not be initialized yet (see open_pool_slot)
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub global_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Pool vault, funded with its rent reserve here
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, pool.key().as_ref()],
+        bump
+    )]
+    pub pool_vault: AccountInfo<'info>,
+    
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<CreatePool>,
     pool_id: u64,
@@ -30,40 +76,449 @@ pub fn handler(
     min_participants: u16,
     charity_address: Pubkey,
     distribution_mode: DistributionMode,
+    options: PoolOptions,
+) -> Result<()> {
+    // Manual IDs can't reach into the registry's range
+    require!(pool_id < REGISTRY_POOL_ID_START, ErrorCode::InvalidPoolId);
+    
+    let authority = ctx.accounts.authority.key();
+    let now = Clock::get()?.unix_timestamp;
+    record_creation(&mut ctx.accounts.creator_state, authority, ctx.bumps.creator_state, now)?;
+    open_pool_slot(&ctx.accounts.global_config)?;
+    
+    init_pool(
+        &mut ctx.accounts.pool,
+        authority,
+        pool_id,
+        goal_type,
+        stake_amount,
+        duration_days,
+        max_participants,
+        min_participants,
+        charity_address,
+        distribution_mode,
+        options,
+        ctx.bumps.pool_vault,
+        ctx.bumps.pool,
+        now,
+    )?;
+    
+    // Create the vault explicitly so every instruction can rely on it existing
+    vault::init_vault(
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.pool_vault,
+    )
+}
+
+/// Throttles repeated creations from the same authority and records this one
+pub(crate) fn record_creation(
+    creator_state: &mut CreatorState,
+    authority: Pubkey,
+    bump: u8,
+    now: i64,
+) -> Result<()> {
+    if creator_state.pools_created > 0 {
+        require!(
+            now >= creator_state.last_created_timestamp.saturating_add(MIN_CREATE_INTERVAL),
+            ErrorCode::RateLimited
+        );
+    }
+    creator_state.authority = authority;
+    creator_state.pools_created = creator_state.pools_created.saturating_add(1);
+    creator_state.last_created_timestamp = now;
+    creator_state.bump = bump;
+    Ok(())
+}
+
+/// Validates pool configuration and initializes the pool account
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn init_pool(
+    pool: &mut CommitmentPool,
+    authority: Pubkey,
+    pool_id: u64,
+    goal_type: GoalType,
+    stake_amount: u64,
+    duration_days: u8,
+    max_participants: u16,
+    min_participants: u16,
+    charity_address: Pubkey,
+    distribution_mode: DistributionMode,
+    options: PoolOptions,
+    vault_bump: u8,
+    bump: u8,
+    now: i64,
 ) -> Result<()> {
-    let pool = &mut ctx.accounts.pool;
-    let clock = Clock::get()?;
+    let PoolOptions {
+        title,
+        category,
+        is_public,
+        max_total_staked,
+        early_bird_count,
+        early_bird_stake,
+        latecomer_policy,
+    } = options;
     
     // Validate inputs
     require!(stake_amount > 0, ErrorCode::InvalidStakeAmount);
     require!(duration_days > 0 && duration_days <= 30, ErrorCode::InvalidStakeAmount);
     require!(max_participants > 0 && max_participants <= 100, ErrorCode::InvalidStakeAmount);
     require!(min_participants > 0 && min_participants <= max_participants, ErrorCode::InvalidStakeAmount);
+    require!(max_total_staked == 0 || max_total_staked >= stake_amount, ErrorCode::InvalidStakeAmount);
+    if early_bird_count > 0 {
+        require!(early_bird_count <= max_participants, ErrorCode::InvalidStakeAmount);
+        require!(early_bird_stake > 0 && early_bird_stake <= stake_amount, ErrorCode::InvalidStakeAmount);
+    }
+    
+    // Validate goal parameters: no impossible targets
+    match &goal_type {
+        GoalType::DailyDCA { amount, .. } => {
+            require!(*amount > 0 && *amount <= MAX_GOAL_AMOUNT, ErrorCode::InvalidGoalParameters);
+        }
+        GoalType::HodlToken { min_balance, .. } => {
+            require!(*min_balance <= MAX_GOAL_AMOUNT, ErrorCode::InvalidGoalParameters);
+        }
+        GoalType::LifestyleHabit { habit_name } => {
+            require!(
+                !habit_name.is_empty() && habit_name.len() <= MAX_HABIT_NAME_LEN,
+                ErrorCode::InvalidGoalParameters
+            );
+        }
+        GoalType::CommitHash { .. } => {}
+    }
     
-    // Validate distribution mode
-    if let DistributionMode::Split { winner_percent } = distribution_mode {
-        require!(winner_percent <= 100, ErrorCode::InvalidStakeAmount);
+    // Validate distribution mode against the charity address
+    let has_charity = charity_address != Pubkey::default();
+    match distribution_mode {
+        DistributionMode::Competitive => {
+            require!(!has_charity, ErrorCode::CharityNotAllowed);
+            // Solo pools (max 1) have nobody to collude with
+            if max_participants > 1 {
+                require!(
+                    min_participants >= MIN_COMPETITIVE_PARTICIPANTS,
+                    ErrorCode::CompetitivePoolTooSmall
+                );
+            }
+        }
+        DistributionMode::Charity => {
+            require!(has_charity, ErrorCode::CharityRequired);
+        }
+        DistributionMode::Split { winner_percent } => {
+            // 0 and 100 are just Charity and Competitive; use those modes instead
+            require!(
+                (1..=99).contains(&winner_percent),
+                ErrorCode::InvalidWinnerPercent
+            );
+            require!(has_charity, ErrorCode::CharityRequired);
+        }
     }
     
+    // Validate title: UTF-8 text followed only by zero padding
+    let title_len = title.iter().position(|&b| b == 0).unwrap_or(title.len());
+    require!(
+        title[title_len..].iter().all(|&b| b == 0) && std::str::from_utf8(&title[..title_len]).is_ok(),
+        ErrorCode::InvalidTitle
+    );
+    
     // Initialize pool
-    pool.authority = ctx.accounts.authority.key();
+    pool.version = CommitmentPool::VERSION;
+    pool.authority = authority;
     pool.pool_id = pool_id;
     pool.goal_type = goal_type;
     pool.stake_amount = stake_amount;
+    pool.early_bird_count = early_bird_count;
+    pool.early_bird_stake = early_bird_stake;
+    pool.latecomer_policy = latecomer_policy;
     pool.duration_days = duration_days;
     pool.max_participants = max_participants;
     pool.min_participants = min_participants;
     pool.participant_count = 0;
     pool.total_staked = 0;
+    pool.max_total_staked = max_total_staked;
     pool.charity_address = charity_address;
     pool.distribution_mode = distribution_mode.clone();
     pool.pool_status = PoolStatus::Pending;
-    pool.start_timestamp = clock.unix_timestamp;
-    pool.end_timestamp = clock.unix_timestamp + (duration_days as i64 * 86400);
-    pool.bump = ctx.bumps.pool;
+    pool.start_timestamp = now;
+    pool.end_timestamp = now + (duration_days as i64 * 86400);
+    require!(pool.end_timestamp > pool.start_timestamp, ErrorCode::InvalidTimestamp);
+    pool.title = title;
+    pool.category = category;
+    pool.is_public = is_public;
+    pool.vault_bump = vault_bump;
+    pool.bump = bump;
     
     msg!("Pool created: {} (mode: {:?}, min: {}, max: {})", 
          pool_id, pool.distribution_mode, min_participants, max_participants);
     Ok(())
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::join_pool::admit;
+    use crate::instructions::verify::check_verifiable;
+    use crate::test_utils;
+    
+    fn habit() -> GoalType {
+        GoalType::LifestyleHabit { habit_name: "Run".to_string() }
+    }
+    
+    /// Runs init_pool on a fresh account with the test defaults
+    fn create(
+        goal_type: GoalType,
+        min_participants: u16,
+        max_participants: u16,
+        charity_address: Pubkey,
+        distribution_mode: DistributionMode,
+        options: PoolOptions,
+    ) -> Result<()> {
+        init_pool(
+            &mut test_utils::pool(),
+            Pubkey::new_unique(),
+            7,
+            goal_type,
+            test_utils::STAKE,
+            test_utils::DAYS,
+            max_participants,
+            min_participants,
+            charity_address,
+            distribution_mode,
+            options,
+            254,
+            253,
+            test_utils::START,
+        )
+    }
+    
+    /// A pool freshly created with `goal_type`, `options` and the test defaults
+    fn created(goal_type: GoalType, options: PoolOptions) -> CommitmentPool {
+        let (pool_key, bump) = pool_address(7);
+        let (_, vault_bump) = vault_address(&pool_key);
+        let mut pool = test_utils::pool();
+        init_pool(
+            &mut pool,
+            Pubkey::new_unique(),
+            7,
+            goal_type,
+            test_utils::STAKE,
+            test_utils::DAYS,
+            10,
+            3,
+            Pubkey::default(),
+            DistributionMode::Competitive,
+            options,
+            vault_bump,
+            bump,
+            test_utils::START,
+        )
+        .unwrap();
+        pool
+    }
+    
+    fn competitive(options: PoolOptions) -> Result<()> {
+        create(habit(), 3, 10, Pubkey::default(), DistributionMode::Competitive, options)
+    }
+    
+    #[test]
+    fn initializes_a_pending_pool_from_now() {
+        let pool = created(habit(), PoolOptions::default());
+        
+        assert_eq!(pool.version, CommitmentPool::VERSION);
+        assert!(pool.pool_status == PoolStatus::Pending);
+        assert_eq!(pool.start_timestamp, test_utils::START);
+        assert_eq!(pool.end_timestamp, test_utils::START + test_utils::DAYS as i64 * 86400);
+        assert_eq!(pool.participant_count, 0);
+        assert_eq!(pool.total_staked, 0);
+    }
+    
+    #[test]
+    fn stored_bumps_rebuild_the_pda_signer_seeds() {
+        let pool = created(habit(), PoolOptions::default());
+        let (pool_key, _) = pool_address(7);
+        
+        let signer = |seeds: &[&[u8]]| Pubkey::create_program_address(seeds, &crate::ID).unwrap();
+        assert_eq!(signer(&[POOL_SEED, &7u64.to_le_bytes(), &[pool.bump]]), pool_key);
+        assert_eq!(signer(&[VAULT_SEED, pool_key.as_ref(), &[pool.vault_bump]]), vault_address(&pool_key).0);
+    }
+    
+    #[test]
+    fn charity_address_must_match_the_mode() {
+        let charity = Pubkey::new_unique();
+        let split = DistributionMode::Split { winner_percent: 50 };
+        
+        // Competitive: no charity
+        assert_eq!(
+            create(habit(), 3, 10, charity, DistributionMode::Competitive, PoolOptions::default()).unwrap_err(),
+            ErrorCode::CharityNotAllowed.into()
+        );
+        assert!(create(habit(), 3, 10, Pubkey::default(), DistributionMode::Competitive, PoolOptions::default()).is_ok());
+        
+        // Charity and Split: a real charity
+        for mode in [DistributionMode::Charity, split] {
+            assert_eq!(
+                create(habit(), 3, 10, Pubkey::default(), mode.clone(), PoolOptions::default()).unwrap_err(),
+                ErrorCode::CharityRequired.into()
+            );
+            assert!(create(habit(), 3, 10, charity, mode, PoolOptions::default()).is_ok());
+        }
+    }
+    
+    #[test]
+    fn competitive_pools_need_three_participants() {
+        assert_eq!(
+            create(habit(), 2, 10, Pubkey::default(), DistributionMode::Competitive, PoolOptions::default()).unwrap_err(),
+            ErrorCode::CompetitivePoolTooSmall.into()
+        );
+        // Solo pools have nobody to collude with
+        assert!(create(habit(), 1, 1, Pubkey::default(), DistributionMode::Competitive, PoolOptions::default()).is_ok());
+    }
+    
+    #[test]
+    fn title_is_utf8_then_zero_padding() {
+        let mut title = [0u8; 32];
+        title[..4].copy_from_slice("Gym!".as_bytes());
+        assert!(competitive(PoolOptions { title, ..Default::default() }).is_ok());
+        
+        // Text after the padding
+        let mut gap = title;
+        gap[10] = b'x';
+        assert_eq!(
+            competitive(PoolOptions { title: gap, ..Default::default() }).unwrap_err(),
+            ErrorCode::InvalidTitle.into()
+        );
+        
+        // Not UTF-8
+        let mut invalid = [0u8; 32];
+        invalid[0] = 0xff;
+        assert_eq!(
+            competitive(PoolOptions { title: invalid, ..Default::default() }).unwrap_err(),
+            ErrorCode::InvalidTitle.into()
+        );
+    }
+    
+    #[test]
+    fn discovery_metadata_is_stored_but_not_enforced() {
+        let mut title = [0u8; 32];
+        title[..5].copy_from_slice("Books".as_bytes());
+        let mut pool = created(habit(), PoolOptions { title, category: 4, is_public: false, ..Default::default() });
+        
+        let mut data = vec![0u8; CommitmentPool::LEN];
+        pool.try_serialize(&mut &mut data[..]).unwrap();
+        let stored = CommitmentPool::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(stored.title, title);
+        assert_eq!(stored.category, 4);
+        assert!(!stored.is_public);
+        
+        // Unlisted pools are still joinable by anyone who has the address
+        assert!(admit(&mut pool, test_utils::START).is_ok());
+    }
+    
+    #[test]
+    fn creations_are_rate_limited_per_authority() {
+        let authority = Pubkey::new_unique();
+        let mut creator_state = test_utils::creator_state();
+        let now = test_utils::START;
+        
+        record_creation(&mut creator_state, authority, 254, now).unwrap();
+        assert_eq!(
+            record_creation(&mut creator_state, authority, 254, now + MIN_CREATE_INTERVAL - 1).unwrap_err(),
+            ErrorCode::RateLimited.into()
+        );
+        record_creation(&mut creator_state, authority, 254, now + MIN_CREATE_INTERVAL).unwrap();
+        
+        assert_eq!(creator_state.authority, authority);
+        assert_eq!(creator_state.pools_created, 2);
+        assert_eq!(creator_state.last_created_timestamp, now + MIN_CREATE_INTERVAL);
+    }
+    
+    #[test]
+    fn commit_hash_pool_is_created_and_verified() {
+        let goal_type = GoalType::CommitHash { description_hash: [9u8; 32] };
+        let mut pool = created(goal_type.clone(), PoolOptions::default());
+        
+        // The hash is anchored on the pool, within the goal_type space estimate
+        let mut data = vec![0u8; CommitmentPool::LEN];
+        pool.try_serialize(&mut &mut data[..]).unwrap();
+        assert!(CommitmentPool::try_deserialize(&mut &data[..]).unwrap().goal_type == goal_type);
+        
+        // Verification works as for any other goal once the pool is running
+        admit(&mut pool, test_utils::START).unwrap();
+        assert!(check_verifiable(&pool, 1, test_utils::START).is_ok());
+    }
+    
+    #[test]
+    fn cap_fits_at_least_one_stake() {
+        for max_total_staked in [0, test_utils::STAKE] {
+            assert!(competitive(PoolOptions { max_total_staked, ..Default::default() }).is_ok());
+        }
+        assert_eq!(
+            competitive(PoolOptions { max_total_staked: test_utils::STAKE - 1, ..Default::default() }).unwrap_err(),
+            ErrorCode::InvalidStakeAmount.into()
+        );
+    }
+    
+    #[test]
+    fn early_bird_stake_is_a_discount_for_some_joiners() {
+        let early_bird = |early_bird_count, early_bird_stake| PoolOptions {
+            early_bird_count,
+            early_bird_stake,
+            ..Default::default()
+        };
+        
+        assert!(competitive(early_bird(10, test_utils::STAKE / 2)).is_ok());
+        // More early birds than slots, a free stake or a premium
+        for (count, stake) in [(11, test_utils::STAKE / 2), (2, 0), (2, test_utils::STAKE + 1)] {
+            assert_eq!(competitive(early_bird(count, stake)).unwrap_err(), ErrorCode::InvalidStakeAmount.into());
+        }
+    }
+    
+    #[test]
+    fn split_percent_is_between_1_and_99() {
+        let charity = Pubkey::new_unique();
+        for winner_percent in [0, 100] {
+            assert_eq!(
+                create(habit(), 3, 10, charity, DistributionMode::Split { winner_percent }, PoolOptions::default())
+                    .unwrap_err(),
+                ErrorCode::InvalidWinnerPercent.into()
+            );
+        }
+        for winner_percent in [1, 99] {
+            assert!(
+                create(habit(), 3, 10, charity, DistributionMode::Split { winner_percent }, PoolOptions::default())
+                    .is_ok()
+            );
+        }
+    }
+    
+    #[test]
+    fn goal_parameters_are_bounded() {
+        let token_mint = Pubkey::new_unique();
+        let invalid = [
+            GoalType::DailyDCA { amount: 0, token_mint },
+            GoalType::DailyDCA { amount: MAX_GOAL_AMOUNT + 1, token_mint },
+            GoalType::HodlToken { token_mint, min_balance: MAX_GOAL_AMOUNT + 1 },
+            GoalType::LifestyleHabit { habit_name: String::new() },
+            GoalType::LifestyleHabit { habit_name: "x".repeat(MAX_HABIT_NAME_LEN + 1) },
+        ];
+        for goal_type in invalid {
+            assert_eq!(
+                create(goal_type, 3, 10, Pubkey::default(), DistributionMode::Competitive, PoolOptions::default())
+                    .unwrap_err(),
+                ErrorCode::InvalidGoalParameters.into()
+            );
+        }
+        
+        let valid = [
+            GoalType::DailyDCA { amount: MAX_GOAL_AMOUNT, token_mint },
+            GoalType::HodlToken { token_mint, min_balance: 0 },
+            GoalType::LifestyleHabit { habit_name: "x".repeat(MAX_HABIT_NAME_LEN) },
+            GoalType::CommitHash { description_hash: [0u8; 32] },
+        ];
+        for goal_type in valid {
+            assert!(
+                create(goal_type, 3, 10, Pubkey::default(), DistributionMode::Competitive, PoolOptions::default())
+                    .is_ok()
+            );
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use crate::pda::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct GetCreatorStats<'info> {
+    #[account(
+        seeds = [CREATOR_SEED, creator_state.authority.as_ref()],
+        bump = creator_state.bump
+    )]
+    pub creator_state: Account<'info, CreatorState>,
+}
+
+pub fn handler(ctx: Context<GetCreatorStats>) -> Result<CreatorStats> {
+    let creator_state = &ctx.accounts.creator_state;
+    
+    Ok(CreatorStats {
+        authority: creator_state.authority,
+        pools_created: creator_state.pools_created,
+        pools_settled: creator_state.pools_settled,
+        total_staked: creator_state.total_staked,
+        total_participants: creator_state.total_participants,
+    })
+}
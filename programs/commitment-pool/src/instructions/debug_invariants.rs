@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+use crate::pda::*;
+use crate::state::*;
+use crate::vault;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct DebugAssertInvariants<'info> {
+    #[account(
+        seeds = [POOL_SEED, pool.pool_id.to_le_bytes().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, CommitmentPool>,
+    
+    /// CHECK: Pool vault, only its balance is read
+    #[account(
+        seeds = [VAULT_SEED, pool.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    pub pool_vault: AccountInfo<'info>,
+    
+    // remaining_accounts: optionally, every participant account of the pool
+}
+
+/// Fails with InvariantViolated, logging which invariant broke
+macro_rules! check {
+    ($cond:expr, $what:expr) => {
+        if !$cond {
+            msg!("Invariant violated: {}", $what);
+            return err!(ErrorCode::InvariantViolated);
+        }
+    };
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DebugAssertInvariants<'info>>,
+) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    check_pool(pool, vault::available_balance(&ctx.accounts.pool_vault)?)?;
+    
+    // If the harness passed the participants, the pool's counters must match them
+    if !ctx.remaining_accounts.is_empty() {
+        let participants = ctx
+            .remaining_accounts
+            .iter()
+            .map(|participant_info| Ok(Account::<Participant>::try_from(participant_info)?.into_inner()))
+            .collect::<Result<Vec<_>>>()?;
+        check_participants(&pool.key(), pool, &participants)?;
+    }
+    
+    msg!("Invariants hold for pool {}", pool.pool_id);
+    Ok(())
+}
+
+/// Invariants of the pool alone, given what the vault holds above its reserve
+pub(crate) fn check_pool(pool: &CommitmentPool, vault_balance: u64) -> Result<()> {
+    // Config stays within the bounds create_pool enforces
+    check!(pool.version == CommitmentPool::VERSION, "version is current");
+    check!(pool.min_participants <= pool.max_participants, "min <= max participants");
+    check!(pool.early_bird_count <= pool.max_participants, "early_bird_count <= max participants");
+    check!(
+        pool.end_timestamp == pool.start_timestamp + pool.duration_days as i64 * 86400,
+        "end = start + duration"
+    );
+    
+    // Counters stay within their caps
+    check!(pool.participant_count <= pool.max_participants, "participant_count <= max");
+    check!(
+        pool.max_total_staked == 0 || pool.total_staked <= pool.max_total_staked,
+        "total_staked <= cap"
+    );
+    
+    // Pending only lasts until the first join
+    if pool.pool_status == PoolStatus::Pending {
+        check!(
+            pool.participant_count == 0 && pool.total_staked == 0,
+            "pending pool has no participants"
+        );
+    }
+    
+    // Every unrescued stake is still in the vault; settlement moves no
+    // lamports, so this holds in every status
+    check!(vault_balance >= pool.total_staked, "vault covers total_staked");
+    Ok(())
+}
+
+/// The pool's counters must match its unrescued participants
+pub(crate) fn check_participants(pool_key: &Pubkey, pool: &CommitmentPool, participants: &[Participant]) -> Result<()> {
+    let mut active_count: u64 = 0;
+    let mut active_stake: u64 = 0;
+    for participant in participants {
+        check!(participant.pool == *pool_key, "participant belongs to pool");
+        if !participant.rescued {
+            active_count += 1;
+            active_stake = active_stake.checked_add(participant.stake_amount).ok_or(ErrorCode::MathOverflow)?;
+        }
+    }
+    check!(active_count == pool.participant_count as u64, "participant_count matches accounts");
+    check!(active_stake == pool.total_staked, "total_staked matches accounts");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::join_pool::admit;
+    use crate::instructions::self_rescue::record_rescue;
+    use crate::test_utils::{self, START, STAKE};
+    
+    /// A pool with `count` participants joined through admit
+    fn joined(count: usize) -> (Pubkey, CommitmentPool, Vec<Participant>) {
+        let pool_key = Pubkey::new_unique();
+        let mut pool = test_utils::pool();
+        let participants = (0..count)
+            .map(|_| test_utils::participant(&pool_key, admit(&mut pool, START).unwrap()))
+            .collect();
+        (pool_key, pool, participants)
+    }
+    
+    #[test]
+    fn joined_pool_holds_invariants() {
+        let (pool_key, pool, participants) = joined(3);
+        assert!(check_pool(&pool, pool.total_staked).is_ok());
+        assert!(check_participants(&pool_key, &pool, &participants).is_ok());
+    }
+    
+    #[test]
+    fn rescues_keep_counters_matching() {
+        let (pool_key, mut pool, mut participants) = joined(3);
+        record_rescue(&mut pool, &mut participants[0]).unwrap();
+        
+        assert!(check_pool(&pool, pool.total_staked).is_ok());
+        assert!(check_participants(&pool_key, &pool, &participants).is_ok());
+    }
+    
+    #[test]
+    fn underfunded_vault_is_caught_in_every_status() {
+        for status in [PoolStatus::Active, PoolStatus::Ended, PoolStatus::Settled, PoolStatus::Cancelled] {
+            let (_, mut pool, _) = joined(3);
+            pool.pool_status = status;
+            assert!(check_pool(&pool, pool.total_staked).is_ok());
+            assert_eq!(
+                check_pool(&pool, pool.total_staked - 1).unwrap_err(),
+                ErrorCode::InvariantViolated.into()
+            );
+        }
+    }
+    
+    #[test]
+    fn pending_pool_with_stake_is_caught() {
+        let mut pool = test_utils::pool();
+        pool.pool_status = PoolStatus::Pending;
+        pool.total_staked = STAKE;
+        assert_eq!(check_pool(&pool, STAKE).unwrap_err(), ErrorCode::InvariantViolated.into());
+    }
+    
+    #[test]
+    fn counter_drift_is_caught() {
+        let (pool_key, mut pool, participants) = joined(3);
+        pool.total_staked -= 1;
+        assert_eq!(
+            check_participants(&pool_key, &pool, &participants).unwrap_err(),
+            ErrorCode::InvariantViolated.into()
+        );
+    }
+    
+    #[test]
+    fn foreign_participant_is_caught() {
+        let (pool_key, pool, mut participants) = joined(3);
+        participants[1].pool = Pubkey::new_unique();
+        assert_eq!(
+            check_participants(&pool_key, &pool, &participants).unwrap_err(),
+            ErrorCode::InvariantViolated.into()
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
+use crate::instructions::global_config::close_pool_slot;
+use crate::pda::*;
 use crate::state::*;
 use crate::errors::ErrorCode;
 
@@ -7,7 +8,7 @@ use crate::errors::ErrorCode;
 pub struct DistributeRewards<'info> {
     #[account(
         mut,
-        seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()],
+        seeds = [POOL_SEED, pool.pool_id.to_le_bytes().as_ref()],
         bump = pool.bump
     )]
     pub pool: Account<'info, CommitmentPool>,
@@ -15,11 +16,27 @@ pub struct DistributeRewards<'info> {
     /// CHECK: Pool vault containing all stakes
     #[account(
         mut,
-        seeds = [b"vault", pool.key().as_ref()],
-        bump
+        seeds = [VAULT_SEED, pool.key().as_ref()],
+        bump = pool.vault_bump
     )]
     pub pool_vault: AccountInfo<'info>,
     
+    /// CHECK: Creator state PDA; pools from before CreatorState have none (see settle_creator_stats)
+    #[account(
+        mut,
+        seeds = [CREATOR_SEED, pool.authority.as_ref()],
+        bump
+    )]
+    pub creator_state: UncheckedAccount<'info>,
+    
+    /// CHECK: Global config PDA; may not be initialized yet (see open_pool_slot)
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub global_config: UncheckedAccount<'info>,
+    
     /// CHECK: AI agent authority (should be verified off-chain)
     pub authority: Signer<'info>,
     
@@ -30,11 +47,7 @@ pub fn handler(ctx: Context<DistributeRewards>) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
     let clock = Clock::get()?;
     
-    // Check pool has ended
-    require!(
-        pool.pool_status == PoolStatus::Ended || clock.unix_timestamp >= pool.end_timestamp,
-        ErrorCode::PoolNotEnded
-    );
+    check_settleable(pool, clock.unix_timestamp)?;
     
     // Mark pool as ended if not already
     if pool.pool_status == PoolStatus::Active {
@@ -53,6 +66,13 @@ pub fn handler(ctx: Context<DistributeRewards>) -> Result<()> {
     // The agent will handle the actual distribution logic off-chain
     // and call individual transfer instructions
     
+    // Roll this pool into the creator's lifetime totals
+    settle_creator_stats(&ctx.accounts.creator_state, pool)?;
+    
+    // Free this pool's slot under the global cap
+    close_pool_slot(&ctx.accounts.global_config, pool)?;
+    
+    // Settled must stay the last state change so a partial run can't be mistaken for a full one
     pool.pool_status = PoolStatus::Settled;
     
     msg!("Pool {} marked as settled. Distribution should be handled by agent.", pool.pool_id);
@@ -60,4 +80,160 @@ pub fn handler(ctx: Context<DistributeRewards>) -> Result<()> {
     Ok(())
 }
 
+/// Whether `pool` can be settled at `now`
+pub(crate) fn check_settleable(pool: &CommitmentPool, now: i64) -> Result<()> {
+    // Never settle a pool twice
+    require!(
+        pool.pool_status != PoolStatus::Settled,
+        ErrorCode::PoolAlreadySettled
+    );
+    
+    // Cancelled pools refund through self_rescue instead
+    require!(
+        pool.pool_status != PoolStatus::Cancelled,
+        ErrorCode::PoolCancelled
+    );
+    
+    // Check pool has ended
+    require!(
+        pool.pool_status == PoolStatus::Ended || now >= pool.end_timestamp,
+        ErrorCode::PoolNotEnded
+    );
+    
+    Ok(())
+}
+
+/// Records `pool` in its creator's stats, if the creator state exists. Pools
+/// created before CreatorState have none and settle without stats.
+fn settle_creator_stats(creator_info: &AccountInfo, pool: &CommitmentPool) -> Result<()> {
+    if creator_info.data_is_empty() {
+        return Ok(());
+    }
+    require_keys_eq!(
+        *creator_info.owner,
+        crate::ID,
+        anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+    );
+    let mut creator_state = CreatorState::try_deserialize(&mut &creator_info.try_borrow_data()?[..])?;
+    record_settlement(&mut creator_state, pool);
+    creator_state.try_serialize(&mut &mut creator_info.try_borrow_mut_data()?[..])
+}
+
+/// Adds a settled `pool` to its creator's lifetime totals
+pub(crate) fn record_settlement(creator_state: &mut CreatorState, pool: &CommitmentPool) {
+    creator_state.pools_settled = creator_state.pools_settled.saturating_add(1);
+    creator_state.total_staked = creator_state.total_staked.saturating_add(pool.total_staked);
+    creator_state.total_participants = creator_state
+        .total_participants
+        .saturating_add(pool.participant_count as u64);
+}
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::create_pool::{record_creation, MIN_CREATE_INTERVAL};
+    use crate::instructions::join_pool::admit;
+    use crate::test_utils::{self, START, STAKE};
+    
+    #[test]
+    fn settles_only_after_the_end() {
+        let pool = test_utils::pool();
+        
+        assert_eq!(
+            check_settleable(&pool, pool.end_timestamp - 1).unwrap_err(),
+            ErrorCode::PoolNotEnded.into()
+        );
+        assert!(check_settleable(&pool, pool.end_timestamp).is_ok());
+    }
+    
+    #[test]
+    fn ended_pool_settles_right_away() {
+        let mut pool = test_utils::pool();
+        pool.pool_status = PoolStatus::Ended;
+        
+        assert!(check_settleable(&pool, pool.start_timestamp).is_ok());
+    }
+    
+    #[test]
+    fn never_settles_twice() {
+        let mut pool = test_utils::pool();
+        check_settleable(&pool, pool.end_timestamp).unwrap();
+        pool.pool_status = PoolStatus::Settled;
+        
+        assert_eq!(
+            check_settleable(&pool, pool.end_timestamp).unwrap_err(),
+            ErrorCode::PoolAlreadySettled.into()
+        );
+    }
+    
+    /// An Active pool of `authority` with `joins` participants
+    fn joined_pool(authority: Pubkey, joins: u16) -> CommitmentPool {
+        let mut pool = test_utils::pool();
+        pool.authority = authority;
+        for _ in 0..joins {
+            admit(&mut pool, START).unwrap();
+        }
+        pool
+    }
+    
+    #[test]
+    fn creator_totals_add_up_across_settled_pools() {
+        let authority = Pubkey::new_unique();
+        let mut creator_state = test_utils::creator_state();
+        
+        for (created_at, joins) in [(START, 2), (START + MIN_CREATE_INTERVAL, 3)] {
+            record_creation(&mut creator_state, authority, 255, created_at).unwrap();
+            let pool = joined_pool(authority, joins);
+            check_settleable(&pool, pool.end_timestamp).unwrap();
+            record_settlement(&mut creator_state, &pool);
+        }
+        
+        assert_eq!(creator_state.pools_created, 2);
+        assert_eq!(creator_state.pools_settled, 2);
+        assert_eq!(creator_state.total_staked, 5 * STAKE);
+        assert_eq!(creator_state.total_participants, 5);
+    }
+    
+    #[test]
+    fn stats_are_written_back_to_the_creator_state() {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = vec![0u8; CreatorState::LEN];
+        test_utils::creator_state().try_serialize(&mut &mut data[..]).unwrap();
+        let creator_info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &crate::ID, false, 0);
+        
+        settle_creator_stats(&creator_info, &joined_pool(Pubkey::new_unique(), 2)).unwrap();
+        
+        let creator_state = CreatorState::try_deserialize(&mut &creator_info.try_borrow_data().unwrap()[..]).unwrap();
+        assert_eq!(creator_state.pools_settled, 1);
+        assert_eq!(creator_state.total_staked, 2 * STAKE);
+        assert_eq!(creator_state.total_participants, 2);
+    }
+    
+    #[test]
+    fn pools_without_a_creator_state_still_settle() {
+        // Migrated v0 pools predate CreatorState, so their creator may have none
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = [0u8; 0];
+        let system_program = Pubkey::default();
+        let creator_info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &system_program, false, 0);
+        
+        assert!(settle_creator_stats(&creator_info, &joined_pool(Pubkey::new_unique(), 2)).is_ok());
+    }
+    
+    #[test]
+    fn creator_state_must_belong_to_the_program() {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = vec![0u8; CreatorState::LEN];
+        test_utils::creator_state().try_serialize(&mut &mut data[..]).unwrap();
+        let other_program = Pubkey::new_unique();
+        let creator_info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &other_program, false, 0);
+        
+        assert_eq!(
+            settle_creator_stats(&creator_info, &test_utils::pool()).unwrap_err(),
+            anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram.into()
+        );
+    }
+}
@@ -0,0 +1,318 @@
+use anchor_lang::prelude::*;
+use crate::pda::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::instructions::self_rescue::SELF_RESCUE_TIMEOUT;
+use crate::program::CommitmentPool as CommitmentPoolProgram;
+
+#[derive(Accounts)]
+pub struct InitGlobalConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = GlobalConfig::LEN,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, CommitmentPoolProgram>,
+    
+    /// Only the program's upgrade authority may set up the config
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetMaxActivePools<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = global_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FreezeAndRefund<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = global_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.pool_id.to_le_bytes().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, CommitmentPool>,
+    
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseStalePool<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+    
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.pool_id.to_le_bytes().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, CommitmentPool>,
+}
+
+pub fn init_handler(ctx: Context<InitGlobalConfig>, max_active_pools: u64) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+    
+    global_config.admin = ctx.accounts.admin.key();
+    global_config.max_active_pools = max_active_pools;
+    global_config.active_pool_count = 0;
+    global_config.initialized_at = Clock::get()?.unix_timestamp;
+    global_config.bump = ctx.bumps.global_config;
+    
+    msg!("Global config initialized (max active pools: {})", max_active_pools);
+    Ok(())
+}
+
+pub fn set_max_handler(ctx: Context<SetMaxActivePools>, max_active_pools: u64) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+    
+    // Lowering below the current count is allowed; it only blocks new pools
+    global_config.max_active_pools = max_active_pools;
+    
+    msg!("Max active pools set to {} ({} active)", max_active_pools, global_config.active_pool_count);
+    Ok(())
+}
+
+pub fn freeze_handler(ctx: Context<FreezeAndRefund>) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+    let pool = &mut ctx.accounts.pool;
+    
+    freeze(global_config, pool)?;
+    
+    msg!("Pool {} frozen by admin; participants can self-rescue their stakes", pool.pool_id);
+    Ok(())
+}
+
+pub fn release_stale_handler(ctx: Context<ReleaseStalePool>) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+    
+    // Only pools that were never settled or cancelled
+    require!(
+        pool.pool_status != PoolStatus::Settled,
+        ErrorCode::PoolAlreadySettled
+    );
+    require!(
+        pool.pool_status != PoolStatus::Cancelled,
+        ErrorCode::PoolCancelled
+    );
+    
+    // Only once participants can self-rescue anyway
+    require!(
+        clock.unix_timestamp >= pool.end_timestamp.saturating_add(SELF_RESCUE_TIMEOUT),
+        ErrorCode::PoolNotStale
+    );
+    
+    release_slot(global_config, pool);
+    pool.pool_status = PoolStatus::Cancelled;
+    
+    msg!("Stale pool {} cancelled; {} pools still active", pool.pool_id, global_config.active_pool_count);
+    Ok(())
+}
+
+/// Cancels `pool` so its participants can self-rescue right away
+pub(crate) fn freeze(global_config: &mut GlobalConfig, pool: &mut CommitmentPool) -> Result<()> {
+    // Settled pools have already paid out; cancelled ones are already frozen
+    require!(
+        pool.pool_status != PoolStatus::Settled,
+        ErrorCode::PoolAlreadySettled
+    );
+    require!(
+        pool.pool_status != PoolStatus::Cancelled,
+        ErrorCode::PoolCancelled
+    );
+    
+    // Cancelled pools never settle, so free their slot now
+    release_slot(global_config, pool);
+    pool.pool_status = PoolStatus::Cancelled;
+    Ok(())
+}
+
+/// Counts a newly created pool against the global cap. Until
+/// init_global_config runs there is no cap, and the empty config is skipped.
+pub(crate) fn open_pool_slot(config_info: &AccountInfo) -> Result<()> {
+    if let Some(mut global_config) = load_config(config_info)? {
+        take_slot(&mut global_config)?;
+        store_config(config_info, &global_config)?;
+    }
+    Ok(())
+}
+
+/// Frees the slot `pool` took at creation, if the config exists
+pub(crate) fn close_pool_slot(config_info: &AccountInfo, pool: &CommitmentPool) -> Result<()> {
+    if let Some(mut global_config) = load_config(config_info)? {
+        release_slot(&mut global_config, pool);
+        store_config(config_info, &global_config)?;
+    }
+    Ok(())
+}
+
+fn load_config(config_info: &AccountInfo) -> Result<Option<GlobalConfig>> {
+    if config_info.data_is_empty() {
+        return Ok(None);
+    }
+    require_keys_eq!(
+        *config_info.owner,
+        crate::ID,
+        anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+    );
+    Ok(Some(GlobalConfig::try_deserialize(&mut &config_info.try_borrow_data()?[..])?))
+}
+
+fn store_config(config_info: &AccountInfo, global_config: &GlobalConfig) -> Result<()> {
+    global_config.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])
+}
+
+fn take_slot(global_config: &mut GlobalConfig) -> Result<()> {
+    let active_pool_count = global_config
+        .active_pool_count
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(
+        global_config.max_active_pools == 0 || active_pool_count <= global_config.max_active_pools,
+        ErrorCode::TooManyActivePools
+    );
+    global_config.active_pool_count = active_pool_count;
+    Ok(())
+}
+
+fn release_slot(global_config: &mut GlobalConfig, pool: &CommitmentPool) {
+    if global_config.counts(pool) {
+        global_config.active_pool_count = global_config.active_pool_count.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::distribute::check_settleable;
+    use crate::instructions::join_pool::admit;
+    use crate::instructions::self_rescue::{check_rescue_available, record_rescue};
+    use crate::test_utils;
+    
+    fn config(max_active_pools: u64) -> GlobalConfig {
+        GlobalConfig {
+            admin: Pubkey::new_unique(),
+            max_active_pools,
+            active_pool_count: 0,
+            initialized_at: test_utils::START,
+            bump: 255,
+        }
+    }
+    
+    #[test]
+    fn slots_are_capped() {
+        let mut global_config = config(2);
+        take_slot(&mut global_config).unwrap();
+        take_slot(&mut global_config).unwrap();
+        
+        assert_eq!(take_slot(&mut global_config).unwrap_err(), ErrorCode::TooManyActivePools.into());
+        assert_eq!(global_config.active_pool_count, 2);
+    }
+    
+    #[test]
+    fn zero_cap_is_unlimited() {
+        let mut global_config = config(0);
+        for _ in 0..100 {
+            take_slot(&mut global_config).unwrap();
+        }
+        assert_eq!(global_config.active_pool_count, 100);
+    }
+    
+    #[test]
+    fn pools_from_before_the_config_free_nothing() {
+        let mut global_config = config(0);
+        take_slot(&mut global_config).unwrap();
+        
+        let mut old_pool = test_utils::pool();
+        old_pool.start_timestamp = global_config.initialized_at - 1;
+        release_slot(&mut global_config, &old_pool);
+        assert_eq!(global_config.active_pool_count, 1);
+        
+        release_slot(&mut global_config, &test_utils::pool());
+        assert_eq!(global_config.active_pool_count, 0);
+    }
+    
+    #[test]
+    fn raising_the_cap_admits_more_pools() {
+        let mut global_config = config(1);
+        take_slot(&mut global_config).unwrap();
+        assert_eq!(take_slot(&mut global_config).unwrap_err(), ErrorCode::TooManyActivePools.into());
+        
+        // What set_max_active_pools does
+        global_config.max_active_pools = 2;
+        take_slot(&mut global_config).unwrap();
+        assert_eq!(global_config.active_pool_count, 2);
+        
+        // Lowering it below the count only blocks new pools
+        global_config.max_active_pools = 1;
+        assert_eq!(take_slot(&mut global_config).unwrap_err(), ErrorCode::TooManyActivePools.into());
+        release_slot(&mut global_config, &test_utils::pool());
+        assert_eq!(global_config.active_pool_count, 1);
+    }
+    
+    #[test]
+    fn frozen_pool_is_rescued_instead_of_settled() {
+        let mut global_config = config(0);
+        take_slot(&mut global_config).unwrap();
+        let mut pool = test_utils::pool();
+        let stake = admit(&mut pool, test_utils::START).unwrap();
+        admit(&mut pool, test_utils::START).unwrap();
+        let mut participant = test_utils::participant(&Pubkey::new_unique(), stake);
+        
+        freeze(&mut global_config, &mut pool).unwrap();
+        assert!(pool.pool_status == PoolStatus::Cancelled);
+        assert_eq!(global_config.active_pool_count, 0);
+        
+        // Rescue opens mid-pool, and the pool can never settle
+        let now = test_utils::START + 86400;
+        check_rescue_available(&pool, &participant, now).unwrap();
+        record_rescue(&mut pool, &mut participant).unwrap();
+        assert_eq!(pool.participant_count, 1);
+        assert_eq!(pool.total_staked, test_utils::STAKE);
+        assert_eq!(check_settleable(&pool, pool.end_timestamp).unwrap_err(), ErrorCode::PoolCancelled.into());
+    }
+    
+    #[test]
+    fn only_unsettled_pools_freeze() {
+        let mut global_config = config(0);
+        for (status, error) in [
+            (PoolStatus::Settled, ErrorCode::PoolAlreadySettled),
+            (PoolStatus::Cancelled, ErrorCode::PoolCancelled),
+        ] {
+            let mut pool = test_utils::pool();
+            pool.pool_status = status;
+            assert_eq!(freeze(&mut global_config, &mut pool).unwrap_err(), error.into());
+        }
+    }
+}
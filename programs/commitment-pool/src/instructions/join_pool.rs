@@ -1,13 +1,14 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
+use crate::pda::*;
 use crate::state::*;
+use crate::vault;
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
 pub struct JoinPool<'info> {
     #[account(
         mut,
-        seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()],
+        seeds = [POOL_SEED, pool.pool_id.to_le_bytes().as_ref()],
         bump = pool.bump
     )]
     pub pool: Account<'info, CommitmentPool>,
@@ -16,7 +17,7 @@ pub struct JoinPool<'info> {
         init,
         payer = participant,
         space = Participant::LEN,
-        seeds = [b"participant", pool.key().as_ref(), participant.key().as_ref()],
+        seeds = [PARTICIPANT_SEED, pool.key().as_ref(), participant.key().as_ref()],
         bump
     )]
     pub participant_account: Account<'info, Participant>,
@@ -27,60 +28,211 @@ pub struct JoinPool<'info> {
     /// CHECK: Pool vault to hold stakes
     #[account(
         mut,
-        seeds = [b"vault", pool.key().as_ref()],
-        bump
+        seeds = [VAULT_SEED, pool.key().as_ref()],
+        bump = pool.vault_bump
     )]
     pub pool_vault: AccountInfo<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<JoinPool>) -> Result<()> {
+pub fn handler(ctx: Context<JoinPool>, tz_offset_seconds: i32) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
     let participant_account = &mut ctx.accounts.participant_account;
     let clock = Clock::get()?;
     
-    // Check pool hasn't started yet or is active
+    // Validate timezone offset
+    check_tz_offset(tz_offset_seconds)?;
+    
+    let stake_amount = admit(pool, clock.unix_timestamp)?;
+    
+    // Transfer stake to pool vault
+    vault::deposit(
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.participant.to_account_info(),
+        &ctx.accounts.pool_vault,
+        stake_amount,
+    )?;
+    
+    // Initialize participant account (stake, with join-time defaults for the rest)
+    participant_account.set_inner(Participant::new(
+        pool.key(),
+        ctx.accounts.participant.key(),
+        stake_amount,
+        tz_offset_seconds,
+        ctx.bumps.participant_account,
+    ));
+    
+    msg!("Participant {} joined pool {}", ctx.accounts.participant.key(), pool.pool_id);
+    Ok(())
+}
+
+/// Checks a join-time timezone offset is within UTC-14 to UTC+14
+pub(crate) fn check_tz_offset(tz_offset_seconds: i32) -> Result<()> {
     require!(
-        pool.pool_status == PoolStatus::Pending || pool.pool_status == PoolStatus::Active,
-        ErrorCode::PoolNotActive
+        (-Participant::MAX_TZ_OFFSET..=Participant::MAX_TZ_OFFSET).contains(&tz_offset_seconds),
+        ErrorCode::InvalidTimezoneOffset
+    );
+    Ok(())
+}
+
+/// Checks one more participant can join, records them on the pool,
+/// and returns the stake they owe
+pub(crate) fn admit(pool: &mut CommitmentPool, now: i64) -> Result<u64> {
+    // Joins lock the moment the pool leaves Pending/Active, so total_staked
+    // can't change once settlement has started
+    require!(
+        matches!(pool.pool_status, PoolStatus::Pending | PoolStatus::Active),
+        ErrorCode::JoinWindowClosed
     );
     
+    // Check join time falls within the pool window
+    require!(
+        now >= pool.start_timestamp && now < pool.end_timestamp,
+        ErrorCode::InvalidTimestamp
+    );
+    
+    // Latecomers have fewer days left to fail, so Forbid pools close after day 1
+    if pool.latecomer_policy == LatecomerPolicy::Forbid {
+        require!(
+            now < pool.start_timestamp.saturating_add(86400),
+            ErrorCode::LatecomerNotAllowed
+        );
+    }
+    
     // Check pool isn't full
     require!(
         pool.participant_count < pool.max_participants,
         ErrorCode::PoolFull
     );
     
-    // Transfer stake to pool vault
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.participant.to_account_info(),
-                to: ctx.accounts.pool_vault.to_account_info(),
-            },
-        ),
-        pool.stake_amount,
-    )?;
+    // Early joiners pay the discounted stake
+    let stake_amount = if pool.participant_count < pool.early_bird_count {
+        pool.early_bird_stake
+    } else {
+        pool.stake_amount
+    };
     
-    // Initialize participant account (only money-related data)
-    participant_account.pool = pool.key();
-    participant_account.wallet = ctx.accounts.participant.key();
-    participant_account.stake_amount = pool.stake_amount;
-    participant_account.bump = ctx.bumps.participant_account;
+    // Check the join wouldn't push total_staked past the cap
+    let new_total_staked = pool
+        .total_staked
+        .checked_add(stake_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(
+        pool.max_total_staked == 0 || new_total_staked <= pool.max_total_staked,
+        ErrorCode::PoolCapReached
+    );
     
     // Update pool
     pool.participant_count += 1;
-    pool.total_staked += pool.stake_amount;
+    pool.total_staked = new_total_staked;
     
     // Start pool if it was pending
     if pool.pool_status == PoolStatus::Pending {
         pool.pool_status = PoolStatus::Active;
     }
     
-    msg!("Participant {} joined pool {}", ctx.accounts.participant.key(), pool.pool_id);
-    Ok(())
+    Ok(stake_amount)
 }
 
-
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{self, START, STAKE};
+    
+    const DAY: i64 = 86400;
+    
+    #[test]
+    fn first_join_activates_the_pool() {
+        let mut pool = test_utils::pool();
+        pool.pool_status = PoolStatus::Pending;
+        
+        assert_eq!(admit(&mut pool, START).unwrap(), STAKE);
+        assert!(pool.pool_status == PoolStatus::Active);
+        assert_eq!(pool.participant_count, 1);
+        assert_eq!(pool.total_staked, STAKE);
+    }
+    
+    #[test]
+    fn joins_only_inside_the_pool_window() {
+        let mut pool = test_utils::pool();
+        let end = pool.end_timestamp;
+        
+        assert_eq!(admit(&mut pool, START - 1).unwrap_err(), ErrorCode::InvalidTimestamp.into());
+        assert_eq!(admit(&mut pool, end).unwrap_err(), ErrorCode::InvalidTimestamp.into());
+        assert!(admit(&mut pool, end - 1).is_ok());
+    }
+    
+    #[test]
+    fn no_joins_once_the_pool_has_ended() {
+        for status in [PoolStatus::Ended, PoolStatus::Settled, PoolStatus::Cancelled] {
+            let mut pool = test_utils::pool();
+            pool.pool_status = status;
+            assert_eq!(admit(&mut pool, START).unwrap_err(), ErrorCode::JoinWindowClosed.into());
+            assert_eq!(pool.total_staked, 0);
+        }
+    }
+    
+    #[test]
+    fn full_pool_rejects_joins() {
+        let mut pool = test_utils::pool();
+        pool.max_participants = 2;
+        admit(&mut pool, START).unwrap();
+        admit(&mut pool, START).unwrap();
+        
+        assert_eq!(admit(&mut pool, START).unwrap_err(), ErrorCode::PoolFull.into());
+        assert_eq!(pool.participant_count, 2);
+    }
+    
+    #[test]
+    fn cap_limits_total_staked() {
+        let mut pool = test_utils::pool();
+        pool.max_total_staked = 2 * STAKE;
+        admit(&mut pool, START).unwrap();
+        admit(&mut pool, START).unwrap();
+        
+        // Slots remain, but the cap is reached
+        assert_eq!(admit(&mut pool, START).unwrap_err(), ErrorCode::PoolCapReached.into());
+        assert_eq!(pool.participant_count, 2);
+        assert_eq!(pool.total_staked, 2 * STAKE);
+    }
+    
+    #[test]
+    fn early_birds_pay_the_discounted_stake() {
+        let mut pool = test_utils::pool();
+        pool.early_bird_count = 2;
+        pool.early_bird_stake = STAKE / 2;
+        
+        assert_eq!(admit(&mut pool, START).unwrap(), STAKE / 2);
+        assert_eq!(admit(&mut pool, START).unwrap(), STAKE / 2);
+        assert_eq!(admit(&mut pool, START).unwrap(), STAKE);
+        assert_eq!(pool.total_staked, 2 * STAKE);
+    }
+    
+    #[test]
+    fn forbid_policy_closes_joins_after_day_one() {
+        let mut pool = test_utils::pool();
+        pool.latecomer_policy = LatecomerPolicy::Forbid;
+        
+        assert!(admit(&mut pool, START + DAY - 1).is_ok());
+        assert_eq!(admit(&mut pool, START + DAY).unwrap_err(), ErrorCode::LatecomerNotAllowed.into());
+        
+        // Allow keeps the pool open until it ends
+        pool.latecomer_policy = LatecomerPolicy::Allow;
+        assert!(admit(&mut pool, START + DAY).is_ok());
+    }
+    
+    #[test]
+    fn timezone_offsets_are_within_14_hours() {
+        let max = Participant::MAX_TZ_OFFSET;
+        for tz_offset_seconds in [0, max, -max] {
+            assert!(check_tz_offset(tz_offset_seconds).is_ok());
+        }
+        for tz_offset_seconds in [max + 1, -max - 1, i32::MAX, i32::MIN] {
+            assert_eq!(
+                check_tz_offset(tz_offset_seconds).unwrap_err(),
+                ErrorCode::InvalidTimezoneOffset.into()
+            );
+        }
+    }
+}
@@ -0,0 +1,298 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::instructions::join_pool::{admit, check_tz_offset};
+use crate::pda::*;
+use crate::state::*;
+use crate::vault;
+use crate::errors::ErrorCode;
+
+/// Most wallets one join_pool_batch call may enroll
+pub const MAX_BATCH_JOIN: usize = 10;
+
+#[derive(Accounts)]
+pub struct JoinPoolBatch<'info> {
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.pool_id.to_le_bytes().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, CommitmentPool>,
+    
+    /// Pays every stake and every participant account's rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// CHECK: Pool vault to hold stakes
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, pool.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    pub pool_vault: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    // remaining_accounts: one uninitialized participant PDA per wallet, in order
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, JoinPoolBatch<'info>>,
+    wallets: Vec<Pubkey>,
+    tz_offset_seconds: i32,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let pool_key = pool.key();
+    let clock = Clock::get()?;
+    
+    check_batch(&wallets, ctx.remaining_accounts.len())?;
+    
+    // Validate timezone offset (shared by the whole batch)
+    check_tz_offset(tz_offset_seconds)?;
+    
+    let rent = Rent::get()?.minimum_balance(Participant::LEN);
+    let mut total_stake: u64 = 0;
+    
+    for (index, participant_info) in ctx.remaining_accounts.iter().enumerate() {
+        let participant = enroll(
+            pool,
+            &pool_key,
+            &wallets,
+            index,
+            participant_info.key,
+            tz_offset_seconds,
+            clock.unix_timestamp,
+        )?;
+        total_stake = total_stake.checked_add(participant.stake_amount).ok_or(ErrorCode::MathOverflow)?;
+        
+        // Create the participant account; fails if the wallet already joined
+        create_participant_account(
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            participant_info,
+            &[PARTICIPANT_SEED, pool_key.as_ref(), participant.wallet.as_ref(), &[participant.bump]],
+            rent,
+        )?;
+        participant.try_serialize(&mut &mut participant_info.try_borrow_mut_data()?[..])?;
+    }
+    
+    // Transfer the combined stake to the pool vault
+    vault::deposit(
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.pool_vault,
+        total_stake,
+    )?;
+    
+    msg!("{} participants joined pool {} (paid by {})",
+         wallets.len(), pool.pool_id, ctx.accounts.payer.key());
+    Ok(())
+}
+
+/// Checks batch size and that each wallet has its participant account
+pub(crate) fn check_batch(wallets: &[Pubkey], participant_accounts: usize) -> Result<()> {
+    require!(
+        !wallets.is_empty() && wallets.len() <= MAX_BATCH_JOIN,
+        ErrorCode::InvalidBatchSize
+    );
+    require!(
+        participant_accounts == wallets.len(),
+        ErrorCode::InvalidBatchSize
+    );
+    Ok(())
+}
+
+/// Admits `wallets[index]` to the pool through `participant_key` and
+/// returns the participant account to write there
+pub(crate) fn enroll(
+    pool: &mut CommitmentPool,
+    pool_key: &Pubkey,
+    wallets: &[Pubkey],
+    index: usize,
+    participant_key: &Pubkey,
+    tz_offset_seconds: i32,
+    now: i64,
+) -> Result<Participant> {
+    let wallet = wallets[index];
+    
+    // Check the wallet isn't already enrolled earlier in this batch
+    require!(!wallets[..index].contains(&wallet), ErrorCode::DuplicateBatchWallet);
+    
+    // Check the account is this wallet's participant PDA
+    let (expected, bump) = participant_address(pool_key, &wallet);
+    require_keys_eq!(*participant_key, expected, ErrorCode::InvalidParticipantAccount);
+    
+    let stake_amount = admit(pool, now)?;
+    Ok(Participant::new(*pool_key, wallet, stake_amount, tz_offset_seconds, bump))
+}
+
+/// Create a participant PDA the way Anchor's `init` does. A plain
+/// create_account fails if someone has already sent lamports to the address,
+/// so a pre-funded account is topped up to rent exemption, then allocated and
+/// assigned instead.
+fn create_participant_account<'info>(
+    system_program: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    rent: u64,
+) -> Result<()> {
+    let current_lamports = target.lamports();
+    
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+                &[seeds],
+            ),
+            rent,
+            Participant::LEN as u64,
+            &crate::ID,
+        );
+    }
+    
+    let shortfall = rent.saturating_sub(current_lamports);
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    
+    // Both fail unless the account is still an empty system account
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: target.clone(),
+            },
+            &[seeds],
+        ),
+        Participant::LEN as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign {
+                account_to_assign: target.clone(),
+            },
+            &[seeds],
+        ),
+        &crate::ID,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{self, START, STAKE};
+    
+    fn wallets(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Pubkey::new_unique()).collect()
+    }
+    
+    /// Enrolls `wallets[index]` through its own participant PDA. Participant
+    /// isn't Debug, so the tests read errors with err().unwrap().
+    fn enroll_at(pool: &mut CommitmentPool, pool_key: &Pubkey, wallets: &[Pubkey], index: usize) -> Result<Participant> {
+        let (participant_key, _) = participant_address(pool_key, &wallets[index]);
+        enroll(pool, pool_key, wallets, index, &participant_key, -3600, START)
+    }
+    
+    #[test]
+    fn batch_size_is_bounded() {
+        assert_eq!(check_batch(&[], 0).unwrap_err(), ErrorCode::InvalidBatchSize.into());
+        assert_eq!(
+            check_batch(&wallets(MAX_BATCH_JOIN + 1), MAX_BATCH_JOIN + 1).unwrap_err(),
+            ErrorCode::InvalidBatchSize.into()
+        );
+        assert!(check_batch(&wallets(MAX_BATCH_JOIN), MAX_BATCH_JOIN).is_ok());
+        
+        // Every wallet needs its account
+        assert_eq!(check_batch(&wallets(3), 2).err().unwrap(), ErrorCode::InvalidBatchSize.into());
+    }
+    
+    #[test]
+    fn batch_enrolls_each_wallet_like_a_join() {
+        let mut pool = test_utils::pool();
+        pool.pool_status = PoolStatus::Pending;
+        let pool_key = Pubkey::new_unique();
+        let batch = wallets(3);
+        
+        for index in 0..batch.len() {
+            let participant = enroll_at(&mut pool, &pool_key, &batch, index).unwrap();
+            let (_, bump) = participant_address(&pool_key, &batch[index]);
+            
+            assert_eq!(participant.version, Participant::VERSION);
+            assert_eq!(participant.pool, pool_key);
+            assert_eq!(participant.wallet, batch[index]);
+            assert_eq!(participant.stake_amount, STAKE);
+            assert_eq!(participant.tz_offset_seconds, -3600);
+            assert_eq!(participant.bump, bump);
+            assert!(!participant.rescued);
+        }
+        
+        assert!(pool.pool_status == PoolStatus::Active);
+        assert_eq!(pool.participant_count, 3);
+        assert_eq!(pool.total_staked, 3 * STAKE);
+    }
+    
+    #[test]
+    fn wallet_needs_its_own_participant_pda() {
+        let mut pool = test_utils::pool();
+        let pool_key = Pubkey::new_unique();
+        let batch = wallets(2);
+        
+        // The first wallet's PDA passed for the second wallet
+        let (first_pda, _) = participant_address(&pool_key, &batch[0]);
+        assert_eq!(
+            enroll(&mut pool, &pool_key, &batch, 1, &first_pda, 0, START).err().unwrap(),
+            ErrorCode::InvalidParticipantAccount.into()
+        );
+        
+        // A PDA derived under another pool
+        let (other_pool_pda, _) = participant_address(&Pubkey::new_unique(), &batch[0]);
+        assert_eq!(
+            enroll(&mut pool, &pool_key, &batch, 0, &other_pool_pda, 0, START).err().unwrap(),
+            ErrorCode::InvalidParticipantAccount.into()
+        );
+        assert_eq!(pool.participant_count, 0);
+    }
+    
+    #[test]
+    fn wallet_enrolls_once_per_batch() {
+        let mut pool = test_utils::pool();
+        let pool_key = Pubkey::new_unique();
+        let wallet = Pubkey::new_unique();
+        let batch = [wallet, Pubkey::new_unique(), wallet];
+        
+        enroll_at(&mut pool, &pool_key, &batch, 0).unwrap();
+        enroll_at(&mut pool, &pool_key, &batch, 1).unwrap();
+        assert_eq!(
+            enroll_at(&mut pool, &pool_key, &batch, 2).err().unwrap(),
+            ErrorCode::DuplicateBatchWallet.into()
+        );
+        assert_eq!(pool.participant_count, 2);
+        assert_eq!(pool.total_staked, 2 * STAKE);
+    }
+    
+    #[test]
+    fn batch_stops_at_a_full_pool() {
+        let mut pool = test_utils::pool();
+        pool.max_participants = 2;
+        let pool_key = Pubkey::new_unique();
+        let batch = wallets(3);
+        
+        enroll_at(&mut pool, &pool_key, &batch, 0).unwrap();
+        enroll_at(&mut pool, &pool_key, &batch, 1).unwrap();
+        assert_eq!(enroll_at(&mut pool, &pool_key, &batch, 2).err().unwrap(), ErrorCode::PoolFull.into());
+    }
+}
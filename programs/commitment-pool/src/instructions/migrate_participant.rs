@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::pda::*;
+use crate::state::*;
+use crate::vault;
+use crate::errors::ErrorCode;
+
+/// Byte offset of the version in every versioned participant layout
+const VERSION_OFFSET: usize = 8;
+
+/// Allocated size of a pre-versioning (version 0) participant. Those have no
+/// version byte, so they're recognized by size; no versioned layout is this small.
+pub const V0_LEN: usize = 81;
+
+/// Version 0 participant layout (before the version byte, notes, self-rescue,
+/// timezones and exit surveys)
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub(crate) struct ParticipantV0 {
+    pub pool: Pubkey,
+    pub wallet: Pubkey,
+    pub stake_amount: u64,
+    pub bump: u8,
+}
+
+impl ParticipantV0 {
+    /// The version 1 participant, with new fields set to what join_pool writes
+    pub(crate) fn upgrade(self) -> Participant {
+        Participant {
+            version: 1,
+            pool: self.pool,
+            wallet: self.wallet,
+            stake_amount: self.stake_amount,
+            note_hash: [0u8; 32],
+            rescued: false,
+            tz_offset_seconds: 0,
+            exit_survey_hash: [0u8; 32],
+            bump: self.bump,
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct MigrateParticipant<'info> {
+    /// CHECK: Parsed by hand, since an older layout won't deserialize as the current one
+    #[account(mut, owner = crate::ID)]
+    pub participant_account: UncheckedAccount<'info>,
+    
+    /// Pays for any extra rent; anyone can migrate, since only the layout changes
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateParticipant>) -> Result<()> {
+    let participant_info = ctx.accounts.participant_account.to_account_info();
+    
+    let participant = {
+        let data = participant_info.try_borrow_data()?;
+        require!(
+            data.len() > VERSION_OFFSET && data[..8] == *Participant::DISCRIMINATOR,
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        
+        // Check there's something to migrate
+        require!(
+            layout_version(&data) < Participant::VERSION,
+            ErrorCode::ParticipantAlreadyMigrated
+        );
+        
+        // Version 0 -> 1
+        ParticipantV0::deserialize(&mut &data[8..])?.upgrade()
+    };
+    
+    // Only real participant PDAs
+    let (expected, _) = participant_address(&participant.pool, &participant.wallet);
+    require_keys_eq!(participant_info.key(), expected, ErrorCode::InvalidParticipantAccount);
+    
+    // Resize to the current layout, topping up rent from the payer
+    let shortfall = Rent::get()?
+        .minimum_balance(Participant::LEN)
+        .saturating_sub(participant_info.lamports());
+    if shortfall > 0 {
+        vault::deposit(
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &participant_info,
+            shortfall,
+        )?;
+    }
+    participant_info.resize(Participant::LEN)?;
+    
+    // Each later layout change adds a step here that rewrites version N data
+    // as N + 1 and fills the new fields with defaults.
+    
+    let mut data = participant_info.try_borrow_mut_data()?;
+    data.fill(0);
+    participant.try_serialize(&mut &mut data[..])?;
+    
+    msg!("Participant {} migrated to version {}", participant.wallet, Participant::VERSION);
+    Ok(())
+}
+
+/// Layout version of raw participant account data
+pub(crate) fn layout_version(data: &[u8]) -> u8 {
+    if data.len() == V0_LEN {
+        0
+    } else {
+        data[VERSION_OFFSET]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn participant_v0() -> ParticipantV0 {
+        ParticipantV0 {
+            pool: Pubkey::new_unique(),
+            wallet: Pubkey::new_unique(),
+            stake_amount: 5_000,
+            bump: 251,
+        }
+    }
+    
+    /// Account data as the version 0 program wrote it
+    fn v0_account_data(participant: &ParticipantV0) -> Vec<u8> {
+        let mut data = Participant::DISCRIMINATOR.to_vec();
+        participant.serialize(&mut data).unwrap();
+        data
+    }
+    
+    #[test]
+    fn versioned_layouts_are_never_v0_sized() {
+        assert_eq!(v0_account_data(&participant_v0()).len(), V0_LEN);
+        assert_ne!(Participant::LEN, V0_LEN);
+    }
+    
+    #[test]
+    fn recognizes_v0_by_size() {
+        let data = v0_account_data(&participant_v0());
+        assert_eq!(layout_version(&data), 0);
+        
+        let mut current = vec![0u8; Participant::LEN];
+        current[VERSION_OFFSET] = Participant::VERSION;
+        assert_eq!(layout_version(&current), Participant::VERSION);
+    }
+    
+    #[test]
+    fn upgrades_v0_to_current_layout() {
+        let original = participant_v0();
+        let data = v0_account_data(&original);
+        
+        let upgraded = ParticipantV0::deserialize(&mut &data[8..]).unwrap().upgrade();
+        let mut migrated = vec![0u8; Participant::LEN];
+        upgraded.try_serialize(&mut &mut migrated[..]).unwrap();
+        
+        let participant = Participant::try_deserialize(&mut &migrated[..]).unwrap();
+        assert_eq!(layout_version(&migrated), Participant::VERSION);
+        assert_eq!(participant.pool, original.pool);
+        assert_eq!(participant.wallet, original.wallet);
+        assert_eq!(participant.stake_amount, 5_000);
+        assert!(!participant.rescued);
+        assert_eq!(participant.tz_offset_seconds, 0);
+        assert_eq!(participant.note_hash, [0u8; 32]);
+        assert_eq!(participant.bump, 251);
+    }
+}
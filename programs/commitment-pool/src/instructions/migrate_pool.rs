@@ -0,0 +1,244 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::pda::*;
+use crate::state::*;
+use crate::vault;
+use crate::errors::ErrorCode;
+
+/// Byte offsets shared by every versioned pool layout
+const VERSION_OFFSET: usize = 8;
+const AUTHORITY_OFFSET: usize = VERSION_OFFSET + 1;
+
+/// Allocated size of a pre-versioning (version 0) pool. Those have no version
+/// byte, so they're recognized by size; no versioned layout is this small.
+pub const V0_LEN: usize = 228;
+
+/// Version 0 pool layout (before the version byte, early-bird tiers, stake
+/// caps, latecomer policy, discovery metadata and the stored vault bump)
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub(crate) struct PoolV0 {
+    pub authority: Pubkey,
+    pub pool_id: u64,
+    pub goal_type: GoalType,
+    pub stake_amount: u64,
+    pub duration_days: u8,
+    pub max_participants: u16,
+    pub min_participants: u16,
+    pub participant_count: u16,
+    pub total_staked: u64,
+    pub charity_address: Pubkey,
+    pub distribution_mode: DistributionMode,
+    pub pool_status: PoolStatus,
+    pub start_timestamp: i64,
+    pub end_timestamp: i64,
+    pub bump: u8,
+}
+
+impl PoolV0 {
+    /// The version 1 pool, with new fields set to what version 0 behaved like
+    pub(crate) fn upgrade(self, vault_bump: u8) -> CommitmentPool {
+        CommitmentPool {
+            version: 1,
+            authority: self.authority,
+            pool_id: self.pool_id,
+            goal_type: self.goal_type,
+            stake_amount: self.stake_amount,
+            early_bird_count: 0,
+            early_bird_stake: 0,
+            latecomer_policy: LatecomerPolicy::Allow,
+            duration_days: self.duration_days,
+            max_participants: self.max_participants,
+            min_participants: self.min_participants,
+            participant_count: self.participant_count,
+            total_staked: self.total_staked,
+            max_total_staked: 0,
+            charity_address: self.charity_address,
+            distribution_mode: self.distribution_mode,
+            pool_status: self.pool_status,
+            start_timestamp: self.start_timestamp,
+            end_timestamp: self.end_timestamp,
+            title: [0u8; 32],
+            category: 0,
+            is_public: true,    // every version 0 pool was listed
+            vault_bump,
+            bump: self.bump,
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct MigratePool<'info> {
+    /// CHECK: Parsed by hand, since an older layout won't deserialize as the current one
+    #[account(mut, owner = crate::ID)]
+    pub pool: UncheckedAccount<'info>,
+    
+    /// CHECK: Pool vault; version 0 pools never funded its rent reserve
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, pool.key().as_ref()],
+        bump
+    )]
+    pub pool_vault: AccountInfo<'info>,
+    
+    /// Pool creator; pays for any extra rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigratePool>) -> Result<()> {
+    let pool_info = ctx.accounts.pool.to_account_info();
+    
+    // Read the version and authority, which every layout keeps up front
+    let (version, authority) = {
+        let data = pool_info.try_borrow_data()?;
+        require!(
+            data.len() >= AUTHORITY_OFFSET + 32 && data[..8] == *CommitmentPool::DISCRIMINATOR,
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        let version = layout_version(&data);
+        let offset = if version == 0 { VERSION_OFFSET } else { AUTHORITY_OFFSET };
+        let mut authority = [0u8; 32];
+        authority.copy_from_slice(&data[offset..offset + 32]);
+        (version, Pubkey::new_from_array(authority))
+    };
+    
+    // Only the pool creator can migrate
+    require_keys_eq!(authority, ctx.accounts.authority.key(), ErrorCode::Unauthorized);
+    
+    // Check there's something to migrate
+    require!(version < CommitmentPool::VERSION, ErrorCode::PoolAlreadyMigrated);
+    
+    // Version 0 -> 1: read the old layout while its size still identifies it
+    let upgraded = if version == 0 {
+        let pool_v0 = PoolV0::deserialize(&mut &pool_info.try_borrow_data()?[8..])?;
+        
+        // The vault now keeps a rent reserve that withdrawals never touch
+        vault::init_vault(
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.pool_vault,
+        )?;
+        Some(pool_v0.upgrade(ctx.bumps.pool_vault))
+    } else {
+        None
+    };
+    
+    // Grow to the current layout, topping up rent from the authority
+    if pool_info.data_len() < CommitmentPool::LEN {
+        let shortfall = Rent::get()?
+            .minimum_balance(CommitmentPool::LEN)
+            .saturating_sub(pool_info.lamports());
+        if shortfall > 0 {
+            vault::deposit(
+                &ctx.accounts.system_program.to_account_info(),
+                &ctx.accounts.authority.to_account_info(),
+                &pool_info,
+                shortfall,
+            )?;
+        }
+        pool_info.resize(CommitmentPool::LEN)?;
+    }
+    
+    if let Some(pool) = upgraded {
+        let mut data = pool_info.try_borrow_mut_data()?;
+        data.fill(0);
+        pool.try_serialize(&mut &mut data[..])?;
+    }
+    
+    // Each later layout change adds a step here that rewrites version N data
+    // as N + 1 and fills the new fields with defaults.
+    
+    pool_info.try_borrow_mut_data()?[VERSION_OFFSET] = CommitmentPool::VERSION;
+    
+    msg!("Pool {} migrated from version {} to {}", pool_info.key(), version, CommitmentPool::VERSION);
+    Ok(())
+}
+
+/// Layout version of raw pool account data
+pub(crate) fn layout_version(data: &[u8]) -> u8 {
+    if data.len() == V0_LEN {
+        0
+    } else {
+        data[VERSION_OFFSET]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn pool_v0() -> PoolV0 {
+        PoolV0 {
+            authority: Pubkey::new_unique(),
+            pool_id: 42,
+            goal_type: GoalType::LifestyleHabit { habit_name: "Screen Time < 3h".to_string() },
+            stake_amount: 1_000_000,
+            duration_days: 7,
+            max_participants: 10,
+            min_participants: 3,
+            participant_count: 2,
+            total_staked: 2_000_000,
+            charity_address: Pubkey::default(),
+            distribution_mode: DistributionMode::Competitive,
+            pool_status: PoolStatus::Active,
+            start_timestamp: 1_700_000_000,
+            end_timestamp: 1_700_604_800,
+            bump: 254,
+        }
+    }
+    
+    /// Account data as the version 0 program wrote it
+    fn v0_account_data(pool: &PoolV0) -> Vec<u8> {
+        let mut data = CommitmentPool::DISCRIMINATOR.to_vec();
+        pool.serialize(&mut data).unwrap();
+        data.resize(V0_LEN, 0);
+        data
+    }
+    
+    #[test]
+    fn versioned_layouts_are_never_v0_sized() {
+        assert_ne!(CommitmentPool::LEN, V0_LEN);
+    }
+    
+    #[test]
+    fn recognizes_v0_by_size() {
+        let data = v0_account_data(&pool_v0());
+        assert_eq!(layout_version(&data), 0);
+        
+        let mut current = vec![0u8; CommitmentPool::LEN];
+        current[VERSION_OFFSET] = CommitmentPool::VERSION;
+        assert_eq!(layout_version(&current), CommitmentPool::VERSION);
+    }
+    
+    #[test]
+    fn v0_authority_sits_right_after_the_discriminator() {
+        let pool = pool_v0();
+        let data = v0_account_data(&pool);
+        assert_eq!(&data[VERSION_OFFSET..VERSION_OFFSET + 32], pool.authority.as_ref());
+    }
+    
+    #[test]
+    fn upgrades_v0_to_current_layout() {
+        let original = pool_v0();
+        let data = v0_account_data(&original);
+        
+        let upgraded = PoolV0::deserialize(&mut &data[8..]).unwrap().upgrade(253);
+        let mut migrated = vec![0u8; CommitmentPool::LEN];
+        upgraded.try_serialize(&mut &mut migrated[..]).unwrap();
+        
+        let pool = CommitmentPool::try_deserialize(&mut &migrated[..]).unwrap();
+        assert_eq!(layout_version(&migrated), CommitmentPool::VERSION);
+        assert_eq!(pool.authority, original.authority);
+        assert_eq!(pool.pool_id, 42);
+        assert_eq!(pool.participant_count, 2);
+        assert_eq!(pool.total_staked, 2_000_000);
+        assert_eq!(pool.end_timestamp, original.end_timestamp);
+        assert!(pool.pool_status == PoolStatus::Active);
+        assert!(pool.latecomer_policy == LatecomerPolicy::Allow);
+        assert_eq!(pool.max_total_staked, 0);
+        assert_eq!(pool.vault_bump, 253);
+        assert_eq!(pool.bump, 254);
+    }
+}
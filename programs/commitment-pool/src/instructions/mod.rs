@@ -1,11 +1,39 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod create_pool;
 pub mod join_pool;
+pub mod join_pool_batch;
 pub mod verify;
 pub mod distribute;
+pub mod update_note;
+pub mod submit_exit_survey;
+pub mod self_rescue;
+pub mod submit_evidence;
+pub mod update_stake_amount;
+pub mod pool_registry;
+pub mod creator_stats;
+pub mod global_config;
+pub mod migrate_pool;
+pub mod migrate_participant;
+#[cfg(any(test, feature = "test-invariants"))]
+pub mod debug_invariants;
 
 pub use create_pool::*;
 pub use join_pool::*;
+pub use join_pool_batch::*;
 pub use verify::*;
 pub use distribute::*;
+pub use update_note::*;
+pub use submit_exit_survey::*;
+pub use self_rescue::*;
+pub use submit_evidence::*;
+pub use update_stake_amount::*;
+pub use pool_registry::*;
+pub use creator_stats::*;
+pub use global_config::*;
+pub use migrate_pool::*;
+pub use migrate_participant::*;
+#[cfg(feature = "test-invariants")]
+pub use debug_invariants::*;
 
 
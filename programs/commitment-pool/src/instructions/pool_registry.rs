@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use crate::instructions::global_config::open_pool_slot;
+use crate::instructions::create_pool::{init_pool, record_creation, REGISTRY_POOL_ID_START};
+use crate::pda::*;
+use crate::state::*;
+use crate::vault;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct InitPoolRegistry<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = PoolRegistry::LEN,
+        seeds = [REGISTRY_SEED],
+        bump
+    )]
+    pub registry: Account<'info, PoolRegistry>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateRegistryPool<'info> {
+    #[account(
+        mut,
+        seeds = [REGISTRY_SEED],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, PoolRegistry>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = CommitmentPool::LEN,
+        seeds = [POOL_SEED, registry.next_pool_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, CommitmentPool>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CreatorState::LEN,
+        seeds = [CREATOR_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub creator_state: Account<'info, CreatorState>,
+    
+    /// CHECK: Global config PDA; may not be initialized yet (see open_pool_slot)
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub global_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Pool vault, funded with its rent reserve here
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, pool.key().as_ref()],
+        bump
+    )]
+    pub pool_vault: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_handler(ctx: Context<InitPoolRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    
+    // Registry IDs live above every manual ID, so the two paths never collide
+    registry.next_pool_id = REGISTRY_POOL_ID_START;
+    registry.bump = ctx.bumps.registry;
+    
+    msg!("Pool registry initialized at {}", registry.next_pool_id);
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_handler(
+    ctx: Context<CreateRegistryPool>,
+    goal_type: GoalType,
+    stake_amount: u64,
+    duration_days: u8,
+    max_participants: u16,
+    min_participants: u16,
+    charity_address: Pubkey,
+    distribution_mode: DistributionMode,
+    options: PoolOptions,
+) -> Result<()> {
+    let pool_id = take_pool_id(&mut ctx.accounts.registry)?;
+    
+    let authority = ctx.accounts.authority.key();
+    let now = Clock::get()?.unix_timestamp;
+    record_creation(&mut ctx.accounts.creator_state, authority, ctx.bumps.creator_state, now)?;
+    open_pool_slot(&ctx.accounts.global_config)?;
+    
+    init_pool(
+        &mut ctx.accounts.pool,
+        authority,
+        pool_id,
+        goal_type,
+        stake_amount,
+        duration_days,
+        max_participants,
+        min_participants,
+        charity_address,
+        distribution_mode,
+        options,
+        ctx.bumps.pool_vault,
+        ctx.bumps.pool,
+        now,
+    )?;
+    
+    vault::init_vault(
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.pool_vault,
+    )
+}
+
+/// Hands out the registry's next pool ID and advances the counter
+pub(crate) fn take_pool_id(registry: &mut PoolRegistry) -> Result<u64> {
+    let pool_id = registry.next_pool_id;
+    registry.next_pool_id = pool_id.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+    Ok(pool_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn registry_pools_get_sequential_ids() {
+        let mut registry = PoolRegistry { next_pool_id: REGISTRY_POOL_ID_START, bump: 255 };
+        
+        let first = take_pool_id(&mut registry).unwrap();
+        let second = take_pool_id(&mut registry).unwrap();
+        assert_eq!(first, REGISTRY_POOL_ID_START);
+        assert_eq!(second, first + 1);
+        assert_eq!(registry.next_pool_id, second + 1);
+        
+        // Each ID is its own pool PDA
+        assert_ne!(pool_address(first).0, pool_address(second).0);
+    }
+    
+    #[test]
+    fn exhausted_registry_stops_handing_out_ids() {
+        let mut registry = PoolRegistry { next_pool_id: u64::MAX, bump: 255 };
+        
+        assert_eq!(take_pool_id(&mut registry).unwrap_err(), ErrorCode::MathOverflow.into());
+        assert_eq!(registry.next_pool_id, u64::MAX);
+    }
+}
@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use crate::pda::*;
+use crate::state::*;
+use crate::vault;
+use crate::errors::ErrorCode;
+
+/// How long past `end_timestamp` a pool must sit before participants can self-rescue
+pub const SELF_RESCUE_TIMEOUT: i64 = 30 * 86400;
+
+#[derive(Accounts)]
+pub struct SelfRescue<'info> {
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.pool_id.to_le_bytes().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, CommitmentPool>,
+    
+    #[account(
+        mut,
+        seeds = [PARTICIPANT_SEED, pool.key().as_ref(), participant.key().as_ref()],
+        bump = participant_account.bump
+    )]
+    pub participant_account: Account<'info, Participant>,
+    
+    #[account(mut)]
+    pub participant: Signer<'info>,
+    
+    /// CHECK: Pool vault holding stakes
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, pool.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    pub pool_vault: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SelfRescue>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let participant_account = &mut ctx.accounts.participant_account;
+    let clock = Clock::get()?;
+    
+    check_rescue_available(pool, participant_account, clock.unix_timestamp)?;
+    
+    // Return own stake from the vault (no redistribution); fails once the
+    // vault no longer holds it
+    let amount = participant_account.stake_amount;
+    vault::withdraw(
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.pool_vault,
+        &ctx.accounts.participant.to_account_info(),
+        &pool.key(),
+        pool.vault_bump,
+        amount,
+    )?;
+    
+    record_rescue(pool, participant_account)?;
+    
+    msg!("Participant {} rescued {} lamports from pool {}",
+         participant_account.wallet, amount, pool.pool_id);
+    Ok(())
+}
+
+/// Whether `participant` may rescue their stake from `pool` at `now`
+pub(crate) fn check_rescue_available(
+    pool: &CommitmentPool,
+    participant: &Participant,
+    now: i64,
+) -> Result<()> {
+    // Right away once the global admin has cancelled the pool, otherwise
+    // long after it should have ended. Settled pools qualify too:
+    // settlement moves no lamports, so settling an abandoned pool must not
+    // lock the stakes in its vault.
+    require!(
+        pool.pool_status == PoolStatus::Cancelled
+            || now >= pool.end_timestamp.saturating_add(SELF_RESCUE_TIMEOUT),
+        ErrorCode::RescueNotAvailable
+    );
+    
+    // Each participant can rescue once
+    require!(!participant.rescued, ErrorCode::AlreadyRescued);
+    
+    Ok(())
+}
+
+/// Marks `participant` rescued and takes them out of the pool's counters
+pub(crate) fn record_rescue(pool: &mut CommitmentPool, participant: &mut Participant) -> Result<()> {
+    // A rescued participant has their full stake back, so they no longer count toward the pool
+    participant.rescued = true;
+    pool.participant_count = pool
+        .participant_count
+        .checked_sub(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    pool.total_staked = pool
+        .total_staked
+        .checked_sub(participant.stake_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::join_pool::admit;
+    use crate::test_utils;
+    
+    #[test]
+    fn rescue_leaves_the_pool_counters() {
+        let mut pool = test_utils::pool();
+        pool.early_bird_count = 1;
+        pool.early_bird_stake = test_utils::STAKE / 2;
+        let early = admit(&mut pool, test_utils::START).unwrap();
+        let regular = admit(&mut pool, test_utils::START).unwrap();
+        let mut participant = test_utils::participant(&Pubkey::new_unique(), early);
+        
+        // The rescue returns what they paid, not the pool's full stake
+        record_rescue(&mut pool, &mut participant).unwrap();
+        
+        assert!(participant.rescued);
+        assert_eq!(pool.participant_count, 1);
+        assert_eq!(pool.total_staked, regular);
+        assert_eq!(
+            check_rescue_available(&pool, &participant, pool.end_timestamp + SELF_RESCUE_TIMEOUT).unwrap_err(),
+            ErrorCode::AlreadyRescued.into()
+        );
+    }
+    
+    #[test]
+    fn rescuing_more_than_the_pool_holds_underflows() {
+        let mut pool = test_utils::pool();
+        let mut participant = test_utils::participant(&Pubkey::new_unique(), test_utils::STAKE);
+        
+        assert_eq!(record_rescue(&mut pool, &mut participant).unwrap_err(), ErrorCode::MathOverflow.into());
+    }
+    
+    #[test]
+    fn rescue_waits_for_the_timeout() {
+        let pool = test_utils::pool();
+        let participant = test_utils::participant(&Pubkey::new_unique(), test_utils::STAKE);
+        let deadline = pool.end_timestamp + SELF_RESCUE_TIMEOUT;
+        
+        assert_eq!(
+            check_rescue_available(&pool, &participant, deadline - 1).unwrap_err(),
+            ErrorCode::RescueNotAvailable.into()
+        );
+        assert!(check_rescue_available(&pool, &participant, deadline).is_ok());
+    }
+    
+    #[test]
+    fn cancelled_pool_is_rescuable_immediately() {
+        let mut pool = test_utils::pool();
+        pool.pool_status = PoolStatus::Cancelled;
+        let participant = test_utils::participant(&Pubkey::new_unique(), test_utils::STAKE);
+        
+        assert!(check_rescue_available(&pool, &participant, pool.start_timestamp).is_ok());
+    }
+    
+    #[test]
+    fn settling_an_abandoned_pool_does_not_block_rescue() {
+        // A griefer settles the pool just before the timeout expires
+        let mut pool = test_utils::pool();
+        pool.pool_status = PoolStatus::Settled;
+        let participant = test_utils::participant(&Pubkey::new_unique(), test_utils::STAKE);
+        
+        assert!(
+            check_rescue_available(&pool, &participant, pool.end_timestamp + SELF_RESCUE_TIMEOUT)
+                .is_ok()
+        );
+    }
+    
+    #[test]
+    fn rescue_only_once() {
+        let pool = test_utils::pool();
+        let mut participant = test_utils::participant(&Pubkey::new_unique(), test_utils::STAKE);
+        participant.rescued = true;
+        
+        assert_eq!(
+            check_rescue_available(&pool, &participant, pool.end_timestamp + SELF_RESCUE_TIMEOUT)
+                .unwrap_err(),
+            ErrorCode::AlreadyRescued.into()
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use crate::pda::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(day: u8)]
+pub struct SubmitEvidence<'info> {
+    #[account(
+        seeds = [POOL_SEED, pool.pool_id.to_le_bytes().as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, CommitmentPool>,
+    
+    #[account(
+        seeds = [PARTICIPANT_SEED, pool.key().as_ref(), wallet.key().as_ref()],
+        bump = participant_account.bump
+    )]
+    pub participant_account: Account<'info, Participant>,
+    
+    #[account(
+        init_if_needed,
+        payer = wallet,
+        space = Evidence::LEN,
+        seeds = [EVIDENCE_SEED, participant_account.key().as_ref(), [day].as_ref()],
+        bump
+    )]
+    pub evidence: Account<'info, Evidence>,
+    
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SubmitEvidence>, day: u8, evidence_hash: [u8; 32]) -> Result<()> {
+    let evidence = &mut ctx.accounts.evidence;
+    let clock = Clock::get()?;
+    
+    record_evidence(
+        evidence,
+        &ctx.accounts.pool,
+        &ctx.accounts.participant_account,
+        ctx.accounts.participant_account.key(),
+        day,
+        evidence_hash,
+        clock.unix_timestamp,
+    )?;
+    evidence.bump = ctx.bumps.evidence;
+    
+    msg!("Participant {} submitted evidence for day {}", ctx.accounts.wallet.key(), day);
+    Ok(())
+}
+
+/// Checks `participant` can submit evidence for `day` at `now` and stores
+/// its hash
+pub(crate) fn record_evidence(
+    evidence: &mut Evidence,
+    pool: &CommitmentPool,
+    participant: &Participant,
+    participant_key: Pubkey,
+    day: u8,
+    evidence_hash: [u8; 32],
+    now: i64,
+) -> Result<()> {
+    // Validate pool is active
+    require!(
+        pool.pool_status == PoolStatus::Active,
+        ErrorCode::PoolNotActive
+    );
+    
+    // Can submit for any day up to the participant's current local day
+    let current_day = participant.current_day(pool.start_timestamp, now);
+    require!(
+        day > 0 && day <= pool.duration_days && (day as i64) <= current_day,
+        ErrorCode::InvalidDay
+    );
+    
+    // Resubmitting replaces the hash; the latest one is what the agent judges
+    evidence.participant = participant_key;
+    evidence.day = day;
+    evidence.evidence_hash = evidence_hash;
+    evidence.submitted_at = now;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{self, START, DAYS};
+    
+    const DAY: i64 = 86400;
+    
+    fn evidence() -> Evidence {
+        Evidence {
+            participant: Pubkey::default(),
+            day: 0,
+            evidence_hash: [0u8; 32],
+            submitted_at: 0,
+            bump: 255,
+        }
+    }
+    
+    #[test]
+    fn resubmitting_replaces_the_hash() {
+        let pool = test_utils::pool();
+        let participant = test_utils::participant(&Pubkey::new_unique(), test_utils::STAKE);
+        let participant_key = Pubkey::new_unique();
+        let mut evidence = evidence();
+        
+        record_evidence(&mut evidence, &pool, &participant, participant_key, 1, [1u8; 32], START).unwrap();
+        assert_eq!(evidence.participant, participant_key);
+        assert_eq!(evidence.day, 1);
+        assert_eq!(evidence.evidence_hash, [1u8; 32]);
+        assert_eq!(evidence.submitted_at, START);
+        
+        // A later submission for the same day wins
+        record_evidence(&mut evidence, &pool, &participant, participant_key, 1, [2u8; 32], START + DAY).unwrap();
+        assert_eq!(evidence.day, 1);
+        assert_eq!(evidence.evidence_hash, [2u8; 32]);
+        assert_eq!(evidence.submitted_at, START + DAY);
+    }
+    
+    #[test]
+    fn evidence_only_for_days_already_reached() {
+        let pool = test_utils::pool();
+        let participant = test_utils::participant(&Pubkey::new_unique(), test_utils::STAKE);
+        let submit = |day, now| {
+            record_evidence(&mut evidence(), &pool, &participant, Pubkey::new_unique(), day, [1u8; 32], now)
+        };
+        
+        assert!(submit(2, START + DAY).is_ok());
+        for (day, now) in [(2, START + DAY - 1), (0, START), (DAYS + 1, START + DAYS as i64 * DAY)] {
+            assert_eq!(submit(day, now).unwrap_err(), ErrorCode::InvalidDay.into());
+        }
+    }
+    
+    #[test]
+    fn evidence_needs_an_active_pool() {
+        let mut pool = test_utils::pool();
+        pool.pool_status = PoolStatus::Ended;
+        let participant = test_utils::participant(&Pubkey::new_unique(), test_utils::STAKE);
+        
+        assert_eq!(
+            record_evidence(&mut evidence(), &pool, &participant, Pubkey::new_unique(), 1, [1u8; 32], START)
+                .unwrap_err(),
+            ErrorCode::PoolNotActive.into()
+        );
+    }
+}
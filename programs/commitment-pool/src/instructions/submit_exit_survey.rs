@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::pda::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SubmitExitSurvey<'info> {
+    #[account(
+        mut,
+        seeds = [PARTICIPANT_SEED, participant_account.pool.as_ref(), wallet.key().as_ref()],
+        bump = participant_account.bump
+    )]
+    pub participant_account: Account<'info, Participant>,
+    
+    pub wallet: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SubmitExitSurvey>, exit_survey_hash: [u8; 32]) -> Result<()> {
+    let participant_account = &mut ctx.accounts.participant_account;
+    set_exit_survey(participant_account, &ctx.accounts.wallet.key(), exit_survey_hash)?;
+    
+    msg!("Participant {} submitted exit survey for pool {}: {:?}",
+         participant_account.wallet, participant_account.pool, exit_survey_hash);
+    Ok(())
+}
+
+/// Sets `participant_account`'s exit survey if `wallet` owns it
+pub(crate) fn set_exit_survey(
+    participant_account: &mut Participant,
+    wallet: &Pubkey,
+    exit_survey_hash: [u8; 32],
+) -> Result<()> {
+    // Only the participant can answer their own survey
+    require_keys_eq!(
+        participant_account.wallet,
+        *wallet,
+        ErrorCode::Unauthorized
+    );
+    
+    // Survey response is opaque user data for off-chain analysis, not used in any logic
+    participant_account.exit_survey_hash = exit_survey_hash;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    
+    #[test]
+    fn survey_hash_is_stored() {
+        let mut participant = test_utils::participant(&Pubkey::new_unique(), test_utils::STAKE);
+        let wallet = participant.wallet;
+        set_exit_survey(&mut participant, &wallet, [5u8; 32]).unwrap();
+        
+        let mut data = [0u8; Participant::LEN];
+        participant.try_serialize(&mut &mut data[..]).unwrap();
+        let read_back = Participant::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(read_back.exit_survey_hash, [5u8; 32]);
+        
+        // Separate from the participant's note and stake
+        assert_eq!(read_back.note_hash, [0u8; 32]);
+        assert_eq!(read_back.stake_amount, test_utils::STAKE);
+    }
+    
+    #[test]
+    fn only_the_participant_answers_the_survey() {
+        let mut participant = test_utils::participant(&Pubkey::new_unique(), test_utils::STAKE);
+        
+        assert_eq!(
+            set_exit_survey(&mut participant, &Pubkey::new_unique(), [5u8; 32]).unwrap_err(),
+            ErrorCode::Unauthorized.into()
+        );
+        assert_eq!(participant.exit_survey_hash, [0u8; 32]);
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::pda::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct UpdateNote<'info> {
+    #[account(
+        mut,
+        seeds = [PARTICIPANT_SEED, participant_account.pool.as_ref(), wallet.key().as_ref()],
+        bump = participant_account.bump
+    )]
+    pub participant_account: Account<'info, Participant>,
+    
+    pub wallet: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateNote>, note_hash: [u8; 32]) -> Result<()> {
+    let participant_account = &mut ctx.accounts.participant_account;
+    set_note(participant_account, &ctx.accounts.wallet.key(), note_hash)?;
+    
+    msg!("Participant {} updated note", participant_account.wallet);
+    Ok(())
+}
+
+/// Sets `participant_account`'s note if `wallet` owns it
+pub(crate) fn set_note(participant_account: &mut Participant, wallet: &Pubkey, note_hash: [u8; 32]) -> Result<()> {
+    // Only the participant can set their own note
+    require_keys_eq!(
+        participant_account.wallet,
+        *wallet,
+        ErrorCode::Unauthorized
+    );
+    
+    // Note is opaque user data (hash or ciphertext reference), not used in any logic
+    participant_account.note_hash = note_hash;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    
+    #[test]
+    fn note_round_trips() {
+        let mut participant = test_utils::participant(&Pubkey::new_unique(), test_utils::STAKE);
+        let wallet = participant.wallet;
+        set_note(&mut participant, &wallet, [7u8; 32]).unwrap();
+        
+        let mut data = [0u8; Participant::LEN];
+        participant.try_serialize(&mut &mut data[..]).unwrap();
+        let read_back = Participant::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(read_back.note_hash, [7u8; 32]);
+        
+        // Setting it again replaces it
+        set_note(&mut participant, &wallet, [0u8; 32]).unwrap();
+        assert_eq!(participant.note_hash, [0u8; 32]);
+    }
+    
+    #[test]
+    fn only_the_participant_sets_the_note() {
+        let mut participant = test_utils::participant(&Pubkey::new_unique(), test_utils::STAKE);
+        
+        assert_eq!(
+            set_note(&mut participant, &Pubkey::new_unique(), [7u8; 32]).unwrap_err(),
+            ErrorCode::Unauthorized.into()
+        );
+        assert_eq!(participant.note_hash, [0u8; 32]);
+    }
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::pda::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct UpdateStakeAmount<'info> {
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.pool_id.to_le_bytes().as_ref()],
+        bump = pool.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub pool: Account<'info, CommitmentPool>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateStakeAmount>, stake_amount: u64) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    
+    apply_stake_update(pool, stake_amount)?;
+    
+    msg!("Pool {} stake updated to {} lamports", pool.pool_id, stake_amount);
+    Ok(())
+}
+
+/// Validates `stake_amount` against the pool and sets it
+pub(crate) fn apply_stake_update(pool: &mut CommitmentPool, stake_amount: u64) -> Result<()> {
+    // Stake is locked once the first participant joins
+    require!(
+        pool.pool_status == PoolStatus::Pending && pool.participant_count == 0,
+        ErrorCode::StakeLocked
+    );
+    
+    // Same rules as create_pool
+    require!(stake_amount > 0, ErrorCode::InvalidStakeAmount);
+    require!(
+        pool.max_total_staked == 0 || pool.max_total_staked >= stake_amount,
+        ErrorCode::InvalidStakeAmount
+    );
+    require!(
+        pool.early_bird_count == 0 || pool.early_bird_stake <= stake_amount,
+        ErrorCode::InvalidStakeAmount
+    );
+    
+    pool.stake_amount = stake_amount;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::join_pool::admit;
+    use crate::test_utils::{self, START, STAKE};
+    
+    fn pending_pool() -> CommitmentPool {
+        let mut pool = test_utils::pool();
+        pool.pool_status = PoolStatus::Pending;
+        pool
+    }
+    
+    #[test]
+    fn updates_an_empty_pending_pool() {
+        let mut pool = pending_pool();
+        apply_stake_update(&mut pool, 2 * STAKE).unwrap();
+        
+        assert_eq!(pool.stake_amount, 2 * STAKE);
+        assert_eq!(admit(&mut pool, START).unwrap(), 2 * STAKE);
+    }
+    
+    #[test]
+    fn stake_locks_at_the_first_join() {
+        let mut pool = pending_pool();
+        admit(&mut pool, START).unwrap();
+        
+        assert_eq!(apply_stake_update(&mut pool, 2 * STAKE).unwrap_err(), ErrorCode::StakeLocked.into());
+        assert_eq!(pool.stake_amount, STAKE);
+    }
+    
+    #[test]
+    fn new_stake_follows_create_rules() {
+        let mut pool = pending_pool();
+        assert_eq!(apply_stake_update(&mut pool, 0).unwrap_err(), ErrorCode::InvalidStakeAmount.into());
+        
+        pool.max_total_staked = STAKE;
+        assert_eq!(apply_stake_update(&mut pool, STAKE + 1).unwrap_err(), ErrorCode::InvalidStakeAmount.into());
+        
+        pool.max_total_staked = 0;
+        pool.early_bird_count = 1;
+        pool.early_bird_stake = STAKE;
+        assert_eq!(apply_stake_update(&mut pool, STAKE - 1).unwrap_err(), ErrorCode::InvalidStakeAmount.into());
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::pda::*;
 use crate::state::*;
 use crate::errors::ErrorCode;
 
@@ -7,20 +8,27 @@ use crate::errors::ErrorCode;
 pub struct VerifyParticipant<'info> {
     #[account(
         mut,
-        seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()],
+        seeds = [POOL_SEED, pool.pool_id.to_le_bytes().as_ref()],
         bump = pool.bump
     )]
     pub pool: Account<'info, CommitmentPool>,
     
     #[account(
         mut,
-        seeds = [b"participant", pool.key().as_ref(), participant.wallet.as_ref()],
+        seeds = [PARTICIPANT_SEED, pool.key().as_ref(), participant.wallet.as_ref()],
         bump = participant.bump
     )]
     pub participant: Account<'info, Participant>,
     
     /// CHECK: AI agent authority (should be verified off-chain)
     pub authority: Signer<'info>,
+    
+    /// Evidence the agent judged for this day, if the participant submitted any
+    #[account(
+        seeds = [EVIDENCE_SEED, participant.key().as_ref(), [day].as_ref()],
+        bump = evidence.bump
+    )]
+    pub evidence: Option<Account<'info, Evidence>>,
 }
 
 pub fn handler(
@@ -30,27 +38,70 @@ pub fn handler(
 ) -> Result<()> {
     let pool = &ctx.accounts.pool;
     let participant = &ctx.accounts.participant;
+    let clock = Clock::get()?;
+    
+    check_verifiable(pool, day, clock.unix_timestamp)?;
+    
+    // Note: Status and days_verified are tracked off-chain (database)
+    // This instruction is kept for logging/auditing purposes only
+    // The agent updates the database before calling this instruction
     
+    msg!("Verified participant {} for day {}: {}", 
+         participant.wallet, day, if passed { "PASSED" } else { "FAILED" });
+    
+    // Anchor the verdict to the evidence it was based on
+    if let Some(evidence) = &ctx.accounts.evidence {
+        msg!("Evidence for day {}: {:?}", day, evidence.evidence_hash);
+    }
+    
+    Ok(())
+}
+
+/// Whether `pool` accepts a verification for `day` at `now`
+pub(crate) fn check_verifiable(pool: &CommitmentPool, day: u8, now: i64) -> Result<()> {
     // Validate pool is active
     require!(
         pool.pool_status == PoolStatus::Active,
         ErrorCode::PoolNotActive
     );
     
+    // Reject verifications from before the pool started
+    require!(
+        now >= pool.start_timestamp,
+        ErrorCode::InvalidTimestamp
+    );
+    
     // Validate day number
     require!(
         day > 0 && day <= pool.duration_days,
         ErrorCode::InvalidDay
     );
     
-    // Note: Status and days_verified are tracked off-chain (database)
-    // This instruction is kept for logging/auditing purposes only
-    // The agent updates the database before calling this instruction
-    
-    msg!("Verified participant {} for day {}: {}", 
-         participant.wallet, day, if passed { "PASSED" } else { "FAILED" });
-    
     Ok(())
 }
 
-
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{self, START};
+    
+    #[test]
+    fn rejects_verifications_before_the_start() {
+        let pool = test_utils::pool();
+        
+        assert_eq!(check_verifiable(&pool, 1, START - 1).unwrap_err(), ErrorCode::InvalidTimestamp.into());
+        assert!(check_verifiable(&pool, 1, START).is_ok());
+    }
+    
+    #[test]
+    fn day_must_be_within_the_duration() {
+        let pool = test_utils::pool();
+        
+        assert_eq!(check_verifiable(&pool, 0, START).unwrap_err(), ErrorCode::InvalidDay.into());
+        assert_eq!(
+            check_verifiable(&pool, pool.duration_days + 1, START).unwrap_err(),
+            ErrorCode::InvalidDay.into()
+        );
+        assert!(check_verifiable(&pool, pool.duration_days, START).is_ok());
+    }
+}
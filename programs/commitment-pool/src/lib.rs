@@ -2,7 +2,12 @@ use anchor_lang::prelude::*;
 
 pub mod errors;
 pub mod instructions;
+pub mod pda;
 pub mod state;
+pub mod vault;
+
+#[cfg(test)]
+mod test_utils;
 
 use instructions::*;
 use state::*;
@@ -14,6 +19,7 @@ pub mod commitment_pool {
     use super::*;
 
     /// Creates a new commitment pool
+    #[allow(clippy::too_many_arguments)]
     pub fn create_pool(
         ctx: Context<CreatePool>,
         pool_id: u64,
@@ -24,6 +30,7 @@ pub mod commitment_pool {
         min_participants: u16,
         charity_address: Pubkey,
         distribution_mode: DistributionMode,
+        options: PoolOptions,
     ) -> Result<()> {
         instructions::create_pool::handler(
             ctx,
@@ -35,12 +42,73 @@ pub mod commitment_pool {
             min_participants,
             charity_address,
             distribution_mode,
+            options,
+        )
+    }
+
+    /// Initializes the global config (program upgrade authority only)
+    pub fn init_global_config(ctx: Context<InitGlobalConfig>, max_active_pools: u64) -> Result<()> {
+        instructions::global_config::init_handler(ctx, max_active_pools)
+    }
+
+    /// Changes the cap on concurrently unsettled pools (config admin only)
+    pub fn set_max_active_pools(ctx: Context<SetMaxActivePools>, max_active_pools: u64) -> Result<()> {
+        instructions::global_config::set_max_handler(ctx, max_active_pools)
+    }
+
+    /// Cancels a misbehaving pool and opens self-rescue refunds (global admin only)
+    pub fn freeze_and_refund(ctx: Context<FreezeAndRefund>) -> Result<()> {
+        instructions::global_config::freeze_handler(ctx)
+    }
+
+    /// Cancels a pool abandoned past the self-rescue timeout and frees its slot (anyone)
+    pub fn release_stale_pool(ctx: Context<ReleaseStalePool>) -> Result<()> {
+        instructions::global_config::release_stale_handler(ctx)
+    }
+
+    /// Initializes the global pool ID registry (one-time, anyone can pay)
+    pub fn init_pool_registry(ctx: Context<InitPoolRegistry>) -> Result<()> {
+        instructions::pool_registry::init_handler(ctx)
+    }
+
+    /// Creates a new commitment pool with the next sequential ID from the registry
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_registry_pool(
+        ctx: Context<CreateRegistryPool>,
+        goal_type: GoalType,
+        stake_amount: u64,
+        duration_days: u8,
+        max_participants: u16,
+        min_participants: u16,
+        charity_address: Pubkey,
+        distribution_mode: DistributionMode,
+        options: PoolOptions,
+    ) -> Result<()> {
+        instructions::pool_registry::create_handler(
+            ctx,
+            goal_type,
+            stake_amount,
+            duration_days,
+            max_participants,
+            min_participants,
+            charity_address,
+            distribution_mode,
+            options,
         )
     }
 
     /// Allows a user to join a pool by staking SOL
-    pub fn join_pool(ctx: Context<JoinPool>) -> Result<()> {
-        instructions::join_pool::handler(ctx)
+    pub fn join_pool(ctx: Context<JoinPool>, tz_offset_seconds: i32) -> Result<()> {
+        instructions::join_pool::handler(ctx, tz_offset_seconds)
+    }
+
+    /// Joins several wallets to a pool in one call, with a single payer covering every stake
+    pub fn join_pool_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, JoinPoolBatch<'info>>,
+        wallets: Vec<Pubkey>,
+        tz_offset_seconds: i32,
+    ) -> Result<()> {
+        instructions::join_pool_batch::handler(ctx, wallets, tz_offset_seconds)
     }
 
     /// Verifies a participant's progress (called by AI agent)
@@ -56,5 +124,57 @@ pub mod commitment_pool {
     pub fn distribute_rewards(ctx: Context<DistributeRewards>) -> Result<()> {
         instructions::distribute::handler(ctx)
     }
+
+    /// Sets the participant's private note hash (called by participant)
+    pub fn update_note(ctx: Context<UpdateNote>, note_hash: [u8; 32]) -> Result<()> {
+        instructions::update_note::handler(ctx, note_hash)
+    }
+
+    /// Stores a participant's exit survey response hash (e.g. after failing or rescuing)
+    pub fn submit_exit_survey(ctx: Context<SubmitExitSurvey>, exit_survey_hash: [u8; 32]) -> Result<()> {
+        instructions::submit_exit_survey::handler(ctx, exit_survey_hash)
+    }
+
+    /// Returns a participant's own stake from an abandoned pool (called by participant)
+    pub fn self_rescue(ctx: Context<SelfRescue>) -> Result<()> {
+        instructions::self_rescue::handler(ctx)
+    }
+
+    /// Commits a hash of a participant's evidence for one day (e.g. a photo)
+    pub fn submit_evidence(
+        ctx: Context<SubmitEvidence>,
+        day: u8,
+        evidence_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::submit_evidence::handler(ctx, day, evidence_hash)
+    }
+
+    /// Changes the stake amount before anyone has joined (called by pool creator)
+    pub fn update_stake_amount(ctx: Context<UpdateStakeAmount>, stake_amount: u64) -> Result<()> {
+        instructions::update_stake_amount::handler(ctx, stake_amount)
+    }
+
+    /// Upgrades a pool account to the current layout version (called by pool creator)
+    pub fn migrate_pool(ctx: Context<MigratePool>) -> Result<()> {
+        instructions::migrate_pool::handler(ctx)
+    }
+
+    /// Upgrades a participant account to the current layout version (anyone can pay)
+    pub fn migrate_participant(ctx: Context<MigrateParticipant>) -> Result<()> {
+        instructions::migrate_participant::handler(ctx)
+    }
+
+    /// Returns a creator's lifetime totals via return data (read-only)
+    pub fn get_creator_stats(ctx: Context<GetCreatorStats>) -> Result<CreatorStats> {
+        instructions::creator_stats::handler(ctx)
+    }
+
+    /// Checks pool invariants for integration harnesses (test-invariants builds only)
+    #[cfg(feature = "test-invariants")]
+    pub fn debug_assert_invariants<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DebugAssertInvariants<'info>>,
+    ) -> Result<()> {
+        instructions::debug_invariants::handler(ctx)
+    }
 }
 
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+
+/// Seed prefix for pool accounts: [POOL_SEED, pool_id (le bytes)]
+pub const POOL_SEED: &[u8] = b"pool";
+
+/// Seed prefix for pool vaults: [VAULT_SEED, pool]
+pub const VAULT_SEED: &[u8] = b"vault";
+
+/// Seed prefix for participant accounts: [PARTICIPANT_SEED, pool, wallet]
+pub const PARTICIPANT_SEED: &[u8] = b"participant";
+
+/// Seed prefix for per-authority creator state: [CREATOR_SEED, authority]
+pub const CREATOR_SEED: &[u8] = b"creator";
+
+/// Seed prefix for daily evidence commitments: [EVIDENCE_SEED, participant, day]
+pub const EVIDENCE_SEED: &[u8] = b"evidence";
+
+/// Seed for the global pool ID registry: [REGISTRY_SEED]
+pub const REGISTRY_SEED: &[u8] = b"registry";
+
+/// Seed for the global config: [CONFIG_SEED]
+pub const CONFIG_SEED: &[u8] = b"config";
+
+/// Derives the pool PDA for a pool ID
+pub fn pool_address(pool_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POOL_SEED, pool_id.to_le_bytes().as_ref()], &crate::ID)
+}
+
+/// Derives the vault PDA holding a pool's stakes
+pub fn vault_address(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED, pool.as_ref()], &crate::ID)
+}
+
+/// Derives the participant PDA for a wallet in a pool
+pub fn participant_address(pool: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PARTICIPANT_SEED, pool.as_ref(), wallet.as_ref()], &crate::ID)
+}
+
+/// Derives the evidence PDA for a participant's day
+pub fn evidence_address(participant: &Pubkey, day: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EVIDENCE_SEED, participant.as_ref(), &[day]], &crate::ID)
+}
+
+/// Derives the creator state PDA for a pool authority
+pub fn creator_address(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CREATOR_SEED, authority.as_ref()], &crate::ID)
+}
+
+/// Derives the global pool ID registry PDA
+pub fn registry_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REGISTRY_SEED], &crate::ID)
+}
+
+/// Derives the global config PDA
+pub fn config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SEED], &crate::ID)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// The off-chain clients derive these PDAs from the literal seeds
+    fn derive(seeds: &[&[u8]]) -> (Pubkey, u8) {
+        Pubkey::find_program_address(seeds, &crate::ID)
+    }
+    
+    #[test]
+    fn pool_address_uses_le_pool_id() {
+        assert_eq!(pool_address(7), derive(&[b"pool", &7u64.to_le_bytes()]));
+        assert_ne!(pool_address(7).0, pool_address(8).0);
+    }
+    
+    #[test]
+    fn vault_address_is_per_pool() {
+        let (pool, _) = pool_address(7);
+        assert_eq!(vault_address(&pool), derive(&[b"vault", pool.as_ref()]));
+        assert_ne!(vault_address(&pool).0, pool);
+    }
+    
+    #[test]
+    fn participant_address_is_per_pool_and_wallet() {
+        let (pool, _) = pool_address(7);
+        let (other_pool, _) = pool_address(8);
+        let wallet = Pubkey::new_unique();
+        
+        assert_eq!(
+            participant_address(&pool, &wallet),
+            derive(&[b"participant", pool.as_ref(), wallet.as_ref()])
+        );
+        assert_ne!(participant_address(&pool, &wallet).0, participant_address(&other_pool, &wallet).0);
+        assert_ne!(
+            participant_address(&pool, &wallet).0,
+            participant_address(&pool, &Pubkey::new_unique()).0
+        );
+    }
+    
+    #[test]
+    fn creator_address_is_per_authority() {
+        let authority = Pubkey::new_unique();
+        assert_eq!(creator_address(&authority), derive(&[b"creator", authority.as_ref()]));
+        assert_ne!(creator_address(&authority).0, creator_address(&Pubkey::new_unique()).0);
+    }
+    
+    #[test]
+    fn registry_address_is_a_singleton() {
+        assert_eq!(registry_address(), derive(&[b"registry"]));
+    }
+    
+    #[test]
+    fn config_address_is_a_singleton() {
+        assert_eq!(config_address(), derive(&[b"config"]));
+        assert_ne!(config_address().0, registry_address().0);
+    }
+    
+    #[test]
+    fn evidence_address_is_per_participant_and_day() {
+        let participant = Pubkey::new_unique();
+        assert_eq!(evidence_address(&participant, 3), derive(&[b"evidence", participant.as_ref(), &[3]]));
+        assert_ne!(evidence_address(&participant, 3).0, evidence_address(&participant, 4).0);
+        assert_ne!(evidence_address(&participant, 3).0, evidence_address(&Pubkey::new_unique(), 3).0);
+    }
+}
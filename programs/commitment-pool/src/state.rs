@@ -3,59 +3,210 @@ use anchor_lang::prelude::*;
 /// Commitment pool account
 #[account]
 pub struct CommitmentPool {
+    pub version: u8,                 // Layout version (kept first so migrate_pool can read it)
     pub authority: Pubkey,           // Pool creator
     pub pool_id: u64,                // Unique pool ID
     pub goal_type: GoalType,         // Type of challenge
     pub stake_amount: u64,           // Amount to stake (lamports)
+    pub early_bird_count: u16,       // First N joiners pay early_bird_stake (0 = disabled)
+    pub early_bird_stake: u64,       // Discounted stake for early joiners (lamports)
+    pub latecomer_policy: LatecomerPolicy, // Whether joins are allowed after day 1
     pub duration_days: u8,            // How many days
     pub max_participants: u16,       // Max pool size
-    pub min_participants: u16,       // Minimum required (1 for solo, 2+ for competitive)
-    pub participant_count: u16,      // Current participants
+    pub min_participants: u16,       // Minimum required (1 for solo, 3+ for competitive)
+    pub participant_count: u16,      // Current participants (excludes rescued)
     pub total_staked: u64,           // Total SOL in pool
+    pub max_total_staked: u64,       // Cap on total_staked (0 = no cap)
     pub charity_address: Pubkey,      // Where loser stakes go (if charity mode)
     pub distribution_mode: DistributionMode, // How to distribute rewards
     pub pool_status: PoolStatus,     // Active, Ended, etc.
     pub start_timestamp: i64,        // When pool starts
     pub end_timestamp: i64,          // When pool ends
+    pub title: [u8; 32],             // Display title (UTF-8, zero-padded)
+    pub category: u8,                // Discovery category (indexer-defined)
+    pub is_public: bool,             // Listed in the public directory
+    pub vault_bump: u8,              // Vault PDA bump (signs vault transfers)
     pub bump: u8,                    // PDA bump
 }
 
 impl CommitmentPool {
+    /// Current layout version; bump it with a migrate_pool step on layout changes
+    pub const VERSION: u8 = 1;
+    
     pub const LEN: usize = 8 +      // discriminator
+        1 +                          // version
         32 +                         // authority
         8 +                          // pool_id
-        100 +                        // goal_type (variable, estimate)
+        100 +                        // goal_type (variable, estimate; CommitHash is 1 + 32)
         8 +                          // stake_amount
+        2 +                          // early_bird_count
+        8 +                          // early_bird_stake
+        1 +                          // latecomer_policy
         1 +                          // duration_days
         2 +                          // max_participants
         2 +                          // min_participants
         2 +                          // participant_count
         8 +                          // total_staked
+        8 +                          // max_total_staked
         32 +                         // charity_address
         4 +                          // distribution_mode
         4 +                          // pool_status
         8 +                          // start_timestamp
         8 +                          // end_timestamp
+        32 +                         // title
+        1 +                          // category
+        1 +                          // is_public
+        1 +                          // vault_bump
         1;                           // bump
 }
 
 /// Participant account
-/// Simplified to only store money-related data.
+/// Stores the stake and rescue flag plus participant-controlled data (note, timezone, exit survey).
 /// Status and verification tracking is handled off-chain (database).
 #[account]
 pub struct Participant {
+    pub version: u8,                 // Layout version (kept first so migrate_participant can read it)
     pub pool: Pubkey,                // Which pool
     pub wallet: Pubkey,              // Participant wallet (for distribution)
     pub stake_amount: u64,           // Their stake (for payout calculation)
+    pub note_hash: [u8; 32],         // Participant-controlled note reference (zeroed if unset)
+    pub rescued: bool,               // Stake reclaimed via self-rescue
+    pub tz_offset_seconds: i32,      // Local time offset from UTC, shifts day boundaries
+    pub exit_survey_hash: [u8; 32],  // Participant's exit survey response hash (zeroed if unset)
     pub bump: u8,                    // PDA bump
 }
 
 impl Participant {
+    /// Current layout version; bump it with a migrate_participant step on layout changes
+    pub const VERSION: u8 = 1;
+    
     pub const LEN: usize = 8 +       // discriminator
+        1 +                           // version
         32 +                          // pool
         32 +                          // wallet
         8 +                           // stake_amount
+        32 +                          // note_hash
+        1 +                           // rescued
+        4 +                           // tz_offset_seconds
+        32 +                          // exit_survey_hash
         1;                            // bump
+    
+    /// Largest timezone offset accepted at join (UTC+14 / UTC-14)
+    pub const MAX_TZ_OFFSET: i32 = 14 * 3600;
+    
+    /// A freshly joined participant: no note or survey yet
+    pub fn new(pool: Pubkey, wallet: Pubkey, stake_amount: u64, tz_offset_seconds: i32, bump: u8) -> Self {
+        Self {
+            version: Self::VERSION,
+            pool,
+            wallet,
+            stake_amount,
+            note_hash: [0u8; 32],
+            rescued: false,
+            tz_offset_seconds,
+            exit_survey_hash: [0u8; 32],
+            bump,
+        }
+    }
+    
+    /// The pool day this participant is on locally (1-based; 0 before their day 1)
+    pub fn current_day(&self, start_timestamp: i64, now: i64) -> i64 {
+        (now + self.tz_offset_seconds as i64 - start_timestamp).div_euclid(86400) + 1
+    }
+}
+
+/// Daily evidence commitment
+/// Hash of a participant's proof (e.g. a photo) for one day, judged off-chain by the agent.
+#[account]
+pub struct Evidence {
+    pub participant: Pubkey,         // Participant account this evidence belongs to
+    pub day: u8,                     // Day the evidence is for (1-based)
+    pub evidence_hash: [u8; 32],     // Hash of the latest submitted evidence
+    pub submitted_at: i64,           // When the latest hash was submitted
+    pub bump: u8,                    // PDA bump
+}
+
+impl Evidence {
+    pub const LEN: usize = 8 +       // discriminator
+        32 +                          // participant
+        1 +                           // day
+        32 +                          // evidence_hash
+        8 +                           // submitted_at
+        1;                            // bump
+}
+
+/// Per-authority creation tracking
+/// Used to rate limit create_pool for a hosted directory.
+#[account]
+pub struct CreatorState {
+    pub authority: Pubkey,           // Pool creator
+    pub pools_created: u64,          // Lifetime pools created
+    pub last_created_timestamp: i64, // When the last pool was created
+    pub pools_settled: u64,          // Lifetime pools settled
+    pub total_staked: u64,           // Lifetime stake across settled pools (lamports)
+    pub total_participants: u64,     // Lifetime participants across settled pools
+    pub bump: u8,                    // PDA bump
+}
+
+impl CreatorState {
+    pub const LEN: usize = 8 +       // discriminator
+        32 +                          // authority
+        8 +                           // pools_created
+        8 +                           // last_created_timestamp
+        8 +                           // pools_settled
+        8 +                           // total_staked
+        8 +                           // total_participants
+        1;                            // bump
+}
+
+/// Lifetime creator totals returned by get_creator_stats
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreatorStats {
+    pub authority: Pubkey,
+    pub pools_created: u64,
+    pub pools_settled: u64,
+    pub total_staked: u64,
+    pub total_participants: u64,
+}
+
+/// Global pool ID registry
+/// Hands out sequential pool IDs for create_registry_pool.
+#[account]
+pub struct PoolRegistry {
+    pub next_pool_id: u64,           // ID assigned to the next registry pool
+    pub bump: u8,                    // PDA bump
+}
+
+impl PoolRegistry {
+    pub const LEN: usize = 8 +       // discriminator
+        8 +                           // next_pool_id
+        1;                            // bump
+}
+
+/// Global config
+/// Circuit breaker capping how many pools can be unsettled at once.
+#[account]
+pub struct GlobalConfig {
+    pub admin: Pubkey,               // May change max_active_pools
+    pub max_active_pools: u64,       // Cap on unsettled pools (0 = no cap)
+    pub active_pool_count: u64,      // Pools created but not yet settled
+    pub initialized_at: i64,         // Pools created earlier were never counted
+    pub bump: u8,                    // PDA bump
+}
+
+impl GlobalConfig {
+    pub const LEN: usize = 8 +       // discriminator
+        32 +                          // admin
+        8 +                           // max_active_pools
+        8 +                           // active_pool_count
+        8 +                           // initialized_at
+        1;                            // bump
+    
+    /// Whether `pool` took a slot at creation; pools created before the config
+    /// existed never did, so closing them must not free anyone else's
+    pub fn counts(&self, pool: &CommitmentPool) -> bool {
+        pool.start_timestamp >= self.initialized_at
+    }
 }
 
 /// Goal type enum
@@ -74,6 +225,10 @@ pub enum GoalType {
     /// Lifestyle habit challenge
     LifestyleHabit {
         habit_name: String,    // E.g., "Screen Time < 3h"
+    },
+    /// Arbitrary off-chain challenge anchored by a hash of its rules
+    CommitHash {
+        description_hash: [u8; 32], // Hash of the agreed challenge description
     }
 }
 
@@ -84,6 +239,7 @@ pub enum PoolStatus {
     Active,       // Currently running
     Ended,        // Pool finished
     Settled,      // Rewards distributed
+    Cancelled,    // Frozen by the global admin; participants reclaim stakes
 }
 
 /// Participant status enum
@@ -95,11 +251,82 @@ pub enum ParticipantStatus {
     Forfeit,      // Left early
 }
 
+/// Optional create_pool / create_registry_pool settings.
+/// The default is an unlisted, uncapped pool with no early-bird tier.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct PoolOptions {
+    pub title: [u8; 32],                   // Display title (UTF-8, zero-padded)
+    pub category: u8,                      // Discovery category (indexer-defined)
+    pub is_public: bool,                   // Listed in the public directory
+    pub max_total_staked: u64,             // Cap on total_staked (0 = no cap)
+    pub early_bird_count: u16,             // First N joiners pay early_bird_stake (0 = disabled)
+    pub early_bird_stake: u64,             // Discounted stake for early joiners (lamports)
+    pub latecomer_policy: LatecomerPolicy, // Whether joins are allowed after day 1
+}
+
+/// Latecomer policy enum
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default)]
+pub enum LatecomerPolicy {
+    #[default]
+    Allow,        // Join any time before end_timestamp
+    Forbid,       // Join only during the first day
+}
+
 /// Distribution mode enum
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
 pub enum DistributionMode {
     Competitive,  // Losers' stakes go to winners
     Charity,      // Losers' stakes go to charity
-    Split { winner_percent: u8 },  // Split between winners and charity (0-100)
+    Split { winner_percent: u8 },  // Split between winners and charity (1-99)
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{self, START};
+    
+    const DAY: i64 = 86400;
+    const HOUR: i32 = 3600;
+    
+    fn participant(tz_offset_seconds: i32) -> Participant {
+        let mut participant = test_utils::participant(&Pubkey::new_unique(), test_utils::STAKE);
+        participant.tz_offset_seconds = tz_offset_seconds;
+        participant
+    }
+    
+    #[test]
+    fn current_day_is_one_based() {
+        let participant = participant(0);
+        
+        assert_eq!(participant.current_day(START, START), 1);
+        assert_eq!(participant.current_day(START, START + DAY - 1), 1);
+        assert_eq!(participant.current_day(START, START + DAY), 2);
+        assert_eq!(participant.current_day(START, START + 6 * DAY + 1), 7);
+    }
+    
+    #[test]
+    fn current_day_is_zero_before_the_start() {
+        assert_eq!(participant(0).current_day(START, START - 1), 0);
+        assert_eq!(participant(0).current_day(START, START - DAY), 0);
+    }
+    
+    #[test]
+    fn timezone_shifts_day_boundaries() {
+        // Ahead of UTC: the local day 2 starts two hours before UTC's
+        let ahead = participant(2 * HOUR);
+        assert_eq!(ahead.current_day(START, START + DAY - 2 * HOUR as i64 - 1), 1);
+        assert_eq!(ahead.current_day(START, START + DAY - 2 * HOUR as i64), 2);
+        
+        // Behind UTC: still the day before locally right after the start
+        let behind = participant(-5 * HOUR);
+        assert_eq!(behind.current_day(START, START), 0);
+        assert_eq!(behind.current_day(START, START + 5 * HOUR as i64), 1);
+    }
+    
+    #[test]
+    fn current_day_handles_the_extreme_offsets() {
+        let max = Participant::MAX_TZ_OFFSET;
+        assert_eq!(participant(max).current_day(START, START), 1);
+        assert_eq!(participant(-max).current_day(START, START), 0);
+    }
+}
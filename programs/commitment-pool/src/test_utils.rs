@@ -0,0 +1,66 @@
+//! Account fixtures shared by the unit tests
+
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+pub const STAKE: u64 = 1_000_000;
+pub const START: i64 = 1_700_000_000;
+pub const DAYS: u8 = 7;
+
+/// An Active competitive pool that started at START and runs for DAYS days
+pub fn pool() -> CommitmentPool {
+    CommitmentPool {
+        version: CommitmentPool::VERSION,
+        authority: Pubkey::new_unique(),
+        pool_id: 1,
+        goal_type: GoalType::LifestyleHabit { habit_name: "Run".to_string() },
+        stake_amount: STAKE,
+        early_bird_count: 0,
+        early_bird_stake: 0,
+        latecomer_policy: LatecomerPolicy::Allow,
+        duration_days: DAYS,
+        max_participants: 10,
+        min_participants: 3,
+        participant_count: 0,
+        total_staked: 0,
+        max_total_staked: 0,
+        charity_address: Pubkey::default(),
+        distribution_mode: DistributionMode::Competitive,
+        pool_status: PoolStatus::Active,
+        start_timestamp: START,
+        end_timestamp: START + DAYS as i64 * 86400,
+        title: [0u8; 32],
+        category: 0,
+        is_public: true,
+        vault_bump: 255,
+        bump: 255,
+    }
+}
+
+/// A participant who staked `stake_amount` in `pool`
+pub fn participant(pool: &Pubkey, stake_amount: u64) -> Participant {
+    Participant {
+        version: Participant::VERSION,
+        pool: *pool,
+        wallet: Pubkey::new_unique(),
+        stake_amount,
+        note_hash: [0u8; 32],
+        rescued: false,
+        tz_offset_seconds: 0,
+        exit_survey_hash: [0u8; 32],
+        bump: 255,
+    }
+}
+
+/// A creator state that hasn't recorded any pools yet
+pub fn creator_state() -> CreatorState {
+    CreatorState {
+        authority: Pubkey::default(),
+        pools_created: 0,
+        last_created_timestamp: 0,
+        pools_settled: 0,
+        total_staked: 0,
+        total_participants: 0,
+        bump: 0,
+    }
+}
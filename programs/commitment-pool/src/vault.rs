@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::errors::ErrorCode;
+use crate::pda::VAULT_SEED;
+
+/// Every pool vault is a data-less system account at [VAULT_SEED, pool].
+/// create_pool funds its rent reserve up front, so every other instruction
+/// can assume it exists, and withdrawals never dip into the reserve.
+pub fn rent_reserve() -> Result<u64> {
+    Ok(Rent::get()?.minimum_balance(0))
+}
+
+/// Lamports held in the vault above its rent reserve
+pub fn available_balance(vault: &AccountInfo) -> Result<u64> {
+    Ok(vault.lamports().saturating_sub(rent_reserve()?))
+}
+
+/// Tops the vault up to its rent reserve (explicit init at pool creation)
+pub fn init_vault<'info>(
+    system_program: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+) -> Result<()> {
+    let shortfall = rent_reserve()?.saturating_sub(vault.lamports());
+    if shortfall > 0 {
+        deposit(system_program, payer, vault, shortfall)?;
+    }
+    Ok(())
+}
+
+/// Moves lamports from a signer into the vault
+pub fn deposit<'info>(
+    system_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            system_program.clone(),
+            system_program::Transfer {
+                from: from.clone(),
+                to: vault.clone(),
+            },
+        ),
+        amount,
+    )
+}
+
+/// Moves lamports out of the vault, signed by the vault PDA
+pub fn withdraw<'info>(
+    system_program: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    pool: &Pubkey,
+    vault_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        available_balance(vault)? >= amount,
+        ErrorCode::InsufficientVaultBalance
+    );
+    
+    let vault_seeds: &[&[u8]] = &[VAULT_SEED, pool.as_ref(), &[vault_bump]];
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Transfer {
+                from: vault.clone(),
+                to: to.clone(),
+            },
+            &[vault_seeds],
+        ),
+        amount,
+    )
+}